@@ -0,0 +1,177 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+#[path = "src/settings.rs"]
+#[allow(dead_code)]
+mod settings;
+
+/// collects all year directories in `src/`, e.g. `src/y2022/`.
+fn find_years(src_dir: &Path) -> Vec<i32> {
+    let mut years: Vec<i32> = fs::read_dir(src_dir)
+        .expect("could not read source directory")
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type().ok()?.is_dir() {
+                return None;
+            }
+            let name = entry.file_name();
+            let year = name.to_str()?.strip_prefix('y')?;
+            if year.len() != 4 {
+                return None;
+            }
+            year.parse::<i32>().ok()
+        })
+        .collect();
+    years.sort_unstable();
+    years
+}
+
+/// collects all days that have a solution module in a year directory, e.g. `src/y2022/01.rs`.
+fn find_solved_days(year_dir: &Path) -> Vec<u8> {
+    let mut days: Vec<u8> = fs::read_dir(year_dir)
+        .expect("could not read solution directory")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?;
+            if stem.len() != 2 {
+                return None;
+            }
+            stem.parse::<u8>().ok().filter(|day| (1..=25).contains(day))
+        })
+        .collect();
+    days.sort_unstable();
+    days
+}
+
+/// Reads a string setting from `aoc.toml`, with the same parser as `src/config.rs`.
+fn read_setting(manifest_dir: &Path, key: &str) -> Option<String> {
+    let contents = fs::read_to_string(manifest_dir.join("aoc.toml")).ok()?;
+    let settings = settings::parse(&contents).unwrap_or_else(|e| panic!("invalid aoc.toml: {}", e));
+    settings
+        .into_iter()
+        .find(|setting| setting.key == key)
+        .and_then(|setting| match setting.value {
+            settings::Value::String(value) => Some(value),
+            _ => None,
+        })
+}
+
+/// collects all `.txt` files of a directory, sorted by name.
+fn find_text_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// With the `embed` feature, generates a lookup of every input and example of every year and
+/// includes the settings, so the binary does not depend on the directory it is run from.
+/// See `read_year_file` in `src/lib.rs` and `Config::read_from` in `src/config.rs`.
+fn embed_files(manifest_dir: &Path, years: &[i32]) -> String {
+    println!("cargo:rerun-if-changed=aoc.toml");
+
+    let config_path = manifest_dir.join("aoc.toml");
+    let mut lookup = if config_path.exists() {
+        format!(
+            "pub const CONFIG: Option<&str> = Some(include_str!({:?}));\n\n",
+            config_path
+        )
+    } else {
+        String::from("pub const CONFIG: Option<&str> = None;\n\n")
+    };
+    lookup.push_str(
+        "pub fn file(folder: &str, year: i32, file_name: &str) -> Option<&'static str> {\n    match (folder, year, file_name) {\n",
+    );
+    for (folder, default_dir) in [
+        ("inputs", "src/y{year}/inputs"),
+        ("examples", "src/y{year}/examples"),
+    ] {
+        let template = read_setting(manifest_dir, &format!("{}_dir", folder))
+            .unwrap_or_else(|| default_dir.to_string());
+        for year in years {
+            let dir = manifest_dir.join(template.replace("{year}", &year.to_string()));
+            println!("cargo:rerun-if-changed={}", dir.display());
+            for path in find_text_files(&dir) {
+                let file_name = path.file_name().unwrap().to_string_lossy();
+                writeln!(
+                    lookup,
+                    "        ({:?}, {}, {:?}) => Some(include_str!({:?})),",
+                    folder, year, file_name, path
+                )
+                .unwrap();
+            }
+        }
+    }
+    lookup.push_str("        _ => None,\n    }\n}\n");
+    lookup
+}
+
+fn main() {
+    // new year directories and solutions can appear anywhere below `src/`.
+    println!("cargo:rerun-if-changed=src");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_dir = Path::new(&manifest_dir).join("src");
+    let years = find_years(&src_dir);
+    let solutions: Vec<(i32, u8)> = years
+        .iter()
+        .copied()
+        .flat_map(|year| {
+            find_solved_days(&src_dir.join(format!("y{}", year)))
+                .into_iter()
+                .map(move |day| (year, day))
+        })
+        .collect();
+
+    // every solution is included as a module of the aggregate runner, see `src/main.rs`.
+    let mut registry = String::new();
+
+    for (year, day) in &solutions {
+        let module_path = src_dir
+            .join(format!("y{}", year))
+            .join(format!("{:02}.rs", day));
+        writeln!(
+            registry,
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod y{}_day_{:02};",
+            module_path, year, day
+        )
+        .unwrap();
+    }
+
+    registry.push_str("\npub fn solutions() -> Vec<advent_of_code::Solution> {\n    vec![\n");
+    for (year, day) in &solutions {
+        writeln!(
+            registry,
+            "        advent_of_code::solution!({}, {}, y{}_day_{:02}),",
+            year, day, year, day
+        )
+        .unwrap();
+    }
+    registry.push_str("    ]\n}\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry)
+        .expect("could not write solution registry");
+
+    if env::var_os("CARGO_FEATURE_EMBED").is_some() {
+        fs::write(
+            Path::new(&out_dir).join("embedded.rs"),
+            embed_files(Path::new(&manifest_dir), &years),
+        )
+        .expect("could not write embedded files");
+    }
+}
@@ -0,0 +1,383 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fs,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Advent of Code website, unless `AOC_BASE_URL` is set.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// file in the home directory that holds the session cookie, unless `AOC_SESSION` is set.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// identifies this tool to the Advent of Code servers, as requested by their maintainer.
+const USER_AGENT: &str = "github.com/Barrokgl/advent-of-code-rust";
+
+/// Offset of the puzzle unlock timezone (UTC-5) to UTC, in seconds.
+const UNLOCK_UTC_OFFSET: i64 = -5 * 60 * 60;
+
+/// Converts days since 1970-01-01 to a `(year, month, day)` date.
+/// See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+/// Converts a `(year, month, day)` date to days since 1970-01-01.
+/// See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let (month, day) = (i64::from(month), i64::from(day));
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Formats seconds since the unix epoch as a UTC date and time, e.g. `2022-12-01 05:00:00 UTC`.
+pub fn format_utc(seconds: u64) -> String {
+    let seconds = seconds as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time = seconds.rem_euclid(86_400);
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Time at which the puzzle of a day is unlocked: midnight UTC-5 on that day of December.
+pub fn unlock_time(year: i32, day: u8) -> SystemTime {
+    let seconds = days_from_civil(year, 12, u32::from(day)) * 86_400 - UNLOCK_UTC_OFFSET;
+    UNIX_EPOCH + Duration::from_secs(seconds.max(0) as u64)
+}
+
+/// Fails if the puzzle of a day does not exist or is not unlocked yet at `now`.
+pub fn check_unlocked(year: i32, day: u8, now: SystemTime) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("{} is not a day between 1 and 25", day));
+    }
+    if year < 2015 {
+        return Err(format!(
+            "there is no event in {}, the first one was in 2015",
+            year
+        ));
+    }
+
+    match unlock_time(year, day).duration_since(now) {
+        Ok(remaining) if !remaining.is_zero() => {
+            let minutes = remaining.as_secs().div_ceil(60);
+            Err(format!(
+                "day {} of {} is not unlocked yet, it unlocks in {}h {}m",
+                day,
+                year,
+                minutes / 60,
+                minutes % 60
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Year of the most recent event at `now`: the current year in December, the previous one otherwise.
+pub fn latest_event_year(now: SystemTime) -> i32 {
+    let seconds = match now.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    };
+    let (year, month, _) = civil_from_days((seconds + UNLOCK_UTC_OFFSET).div_euclid(86_400));
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION` or from `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or("could not find your home directory, set AOC_SESSION instead")?;
+    let path = PathBuf::from(home).join(SESSION_FILE);
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(format!("the session file \"{}\" is empty", path.display())),
+        Err(e) => Err(format!(
+            "could not read the session cookie from AOC_SESSION or \"{}\": {}",
+            path.display(),
+            e
+        )),
+    }
+}
+
+/// Base URL of the Advent of Code website, can be pointed to a stand-in server with `AOC_BASE_URL`.
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_BASE_URL))
+}
+
+/// Minimal client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client for the website at `AOC_BASE_URL` with the session from [`read_session`].
+    pub fn from_env() -> Result<Client, String> {
+        Ok(Client::new(&base_url(), &read_session()?))
+    }
+
+    /// Session cookie the client authenticates with.
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn read_response(
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("could not read the response from {}: {}", url, e)),
+            Err(ureq::Error::Status(400 | 401, _)) => Err(String::from(
+                "the session cookie is invalid or expired, refresh it and try again",
+            )),
+            Err(ureq::Error::Status(404, _)) => Err(format!(
+                "{} was not found, is the puzzle unlocked yet?",
+                url
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(format!("{} responded with status {}", url, status))
+            }
+            Err(e) => Err(format!("could not reach {}: {}", url, e)),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String, String> {
+        let url = self.url(path);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        Client::read_response(&url, response)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(form);
+        Client::read_response(&url, response)
+    }
+
+    /// Fetches the personal puzzle input of a day.
+    pub fn get_input(&self, year: i32, day: u8) -> Result<String, String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Fetches the HTML page of a puzzle. Part two is included once part one is solved.
+    pub fn get_puzzle(&self, year: i32, day: u8) -> Result<String, String> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Submits an answer and returns the HTML page of the response.
+    pub fn post_answer(
+        &self,
+        year: i32,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, String> {
+        self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    /// Answers a single request with `status` and `body` and returns the received request.
+    pub(crate) fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_get_input() {
+        let (base_url, server) = serve_once(200, "1000\n2000\n");
+        let client = Client::new(&format!("{}/", base_url), "abc123");
+
+        assert_eq!(client.get_input(2022, 1), Ok(String::from("1000\n2000\n")));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let client = Client::new(&base_url, "abc123");
+
+        let response = client.post_answer(2022, 5, 2, "MCD").unwrap();
+        assert!(response.contains("That's the right answer!"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=MCD"));
+    }
+
+    #[test]
+    fn test_get_input_errors() {
+        let (base_url, _) = serve_once(400, "Puzzle inputs differ by user.");
+        let error = Client::new(&base_url, "expired").get_input(2022, 1);
+        assert!(error.unwrap_err().contains("session cookie is invalid"));
+
+        let (base_url, _) = serve_once(404, "Not Found");
+        let error = Client::new(&base_url, "abc123").get_input(2022, 25);
+        assert!(error.unwrap_err().contains("is the puzzle unlocked yet?"));
+    }
+
+    #[test]
+    fn test_latest_event_year() {
+        let at = |seconds: u64| UNIX_EPOCH + Duration::from_secs(seconds);
+        // 2022-11-30T12:00:00Z
+        assert_eq!(latest_event_year(at(1_669_809_600)), 2021);
+        // 2022-12-01T04:59:59Z, still november in UTC-5.
+        assert_eq!(latest_event_year(at(1_669_870_799)), 2021);
+        // 2022-12-01T05:00:00Z
+        assert_eq!(latest_event_year(at(1_669_870_800)), 2022);
+        // 2023-01-15T00:00:00Z
+        assert_eq!(latest_event_year(at(1_673_740_800)), 2022);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_327), (2022, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        for days in [-1, 0, 59, 19_327, 20_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_utc(1_669_870_800 + 3_723), "2022-12-01 06:02:03 UTC");
+    }
+
+    #[test]
+    fn test_check_unlocked() {
+        // 2022-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2022, 1),
+            UNIX_EPOCH + Duration::from_secs(1_669_870_800)
+        );
+
+        let now = unlock_time(2022, 10);
+        assert!(check_unlocked(2022, 10, now).is_ok());
+        assert!(check_unlocked(2022, 9, now).is_ok());
+        assert!(check_unlocked(2021, 25, now).is_ok());
+        assert_eq!(
+            check_unlocked(2022, 11, now),
+            Err(String::from(
+                "day 11 of 2022 is not unlocked yet, it unlocks in 24h 0m"
+            ))
+        );
+        assert!(check_unlocked(2022, 26, now).is_err());
+        assert!(check_unlocked(2014, 1, now).is_err());
+    }
+}
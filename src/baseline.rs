@@ -0,0 +1,291 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::{output::report_to_json, style, PartResult, ANSI_BOLD, ANSI_RESET};
+
+/// slowdown in percent above which a part is flagged, unless `--threshold` is passed.
+const DEFAULT_THRESHOLD: f64 = 10_f64;
+
+/// Baseline flags of the aggregate runner.
+pub struct Args {
+    /// `--save-baseline <file>`: write the timings of this run to a file.
+    pub save: Option<PathBuf>,
+    /// `--baseline <file>`: compare the timings of this run to a saved baseline.
+    pub compare: Option<PathBuf>,
+    /// `--threshold <percent>`: slowdown that is reported as a regression.
+    pub threshold: f64,
+}
+
+impl Args {
+    /// Takes the baseline flags from the arguments.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+        Ok(Args {
+            save: args.opt_value_from_str("--save-baseline")?,
+            compare: args.opt_value_from_str("--baseline")?,
+            threshold: args
+                .opt_value_from_str("--threshold")?
+                .unwrap_or(DEFAULT_THRESHOLD),
+        })
+    }
+}
+
+/// Saved timings of the solved parts of a year, keyed by `(year, day, part)`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    year: i32,
+    timings: HashMap<(i32, u8, u8), Duration>,
+}
+
+impl Baseline {
+    /// Parses a baseline from the JSON document written by `--format json` or `--save-baseline`.
+    pub fn parse(json: &str) -> Result<Baseline, String> {
+        let document: JsonValue = json.parse().map_err(|e| format!("{}", e))?;
+        let document: &HashMap<String, JsonValue> = document
+            .get()
+            .ok_or("expected an object with a \"year\" and a \"parts\" array")?;
+        let year = document
+            .get("year")
+            .and_then(|year| year.get::<f64>())
+            .map(|&year| year as i32)
+            .ok_or("expected a number for \"year\", save the baseline again")?;
+        let parts: &Vec<JsonValue> = document
+            .get("parts")
+            .and_then(|parts| parts.get())
+            .ok_or("expected a \"parts\" array")?;
+
+        let mut timings = HashMap::new();
+        for part in parts {
+            let part: &HashMap<String, JsonValue> =
+                part.get().ok_or("expected \"parts\" to contain objects")?;
+            let number = |key: &str| -> Result<f64, String> {
+                part.get(key)
+                    .and_then(|value| value.get::<f64>())
+                    .copied()
+                    .ok_or(format!("expected a number for \"{}\"", key))
+            };
+
+            if part.get("answer").is_none_or(JsonValue::is_null) {
+                continue;
+            }
+
+            timings.insert(
+                (year, number("day")? as u8, number("part")? as u8),
+                Duration::from_nanos(number("elapsed_ns")? as u64),
+            );
+        }
+
+        Ok(Baseline { year, timings })
+    }
+
+    /// Reads the baseline of `year`. Baselines of other years are rejected, their days are unrelated.
+    pub fn read(path: &Path, year: i32) -> Result<Baseline, String> {
+        let contents = fs::read_to_string(path).map_err(|e| format!("{}", e))?;
+        let baseline = Baseline::parse(&contents)?;
+        if baseline.year != year {
+            return Err(format!(
+                "the baseline was saved for {}, not for {}",
+                baseline.year, year
+            ));
+        }
+        Ok(baseline)
+    }
+
+    /// Writes the timings of a run so that later runs can be compared against it.
+    pub fn save(path: &Path, year: i32, results: &[PartResult]) -> std::io::Result<()> {
+        fs::write(path, report_to_json(year, results))
+    }
+
+    /// Compares all solved results of `year` to their baseline timings.
+    pub fn compare(&self, year: i32, results: &[PartResult]) -> Vec<Comparison> {
+        results
+            .iter()
+            .filter(|result| result.answer.is_some())
+            .filter_map(|result| {
+                self.timings
+                    .get(&(year, result.day, result.part))
+                    .map(|baseline| Comparison {
+                        day: result.day,
+                        part: result.part,
+                        baseline: *baseline,
+                        current: result.elapsed,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Timing of a part compared to its baseline.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Change in percent, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        if self.baseline.is_zero() {
+            0_f64
+        } else {
+            (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64) * 100_f64
+        }
+    }
+
+    pub fn is_slowdown(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+
+    pub fn format(&self, threshold: f64) -> String {
+        let (sign, delta) = if self.current >= self.baseline {
+            ('+', self.current - self.baseline)
+        } else {
+            ('-', self.baseline - self.current)
+        };
+        format!(
+            "Day {:02} Part {}: {:.2?} → {:.2?} ({}{:.2?}, {:+.1}%){}",
+            self.day,
+            self.part,
+            self.baseline,
+            self.current,
+            sign,
+            delta,
+            self.percent(),
+            if self.is_slowdown(threshold) {
+                " ❌ slower"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// Prints the comparison to a baseline and returns `true` if a part got slower than allowed.
+/// The comparison is written to stderr so it does not interfere with machine-readable output.
+pub fn print_comparison(comparisons: &[Comparison], threshold: f64) -> bool {
+    eprintln!(
+        "{}Baseline comparison{} (threshold: {:.1}%)",
+        style(ANSI_BOLD),
+        style(ANSI_RESET),
+        threshold
+    );
+    comparisons
+        .iter()
+        .for_each(|comparison| eprintln!("{}", comparison.format(threshold)));
+    comparisons
+        .iter()
+        .any(|comparison| comparison.is_slowdown(threshold))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let results = [
+            PartResult::answered(1, 1, Some("24000"), Duration::from_micros(100)),
+            PartResult::answered(1, 2, None, Duration::from_micros(0)),
+            PartResult::answered(2, 1, Some("15"), Duration::from_micros(3)),
+        ];
+        let baseline = Baseline::parse(&report_to_json(2022, &results)).unwrap();
+
+        assert_eq!(baseline.year, 2022);
+        assert_eq!(baseline.timings.len(), 2);
+        assert_eq!(
+            baseline.timings.get(&(2022, 1, 1)),
+            Some(&Duration::from_micros(100))
+        );
+        assert_eq!(baseline.timings.get(&(2022, 1, 2)), None);
+
+        assert!(Baseline::parse("[]").is_err());
+        assert!(Baseline::parse("{\"year\":2022,\"parts\":[1]}").is_err());
+        assert!(Baseline::parse("{\"year\":2022,\"parts\":[{\"answer\":\"1\"}]}").is_err());
+        assert!(Baseline::parse("{\"year\":2022,\"parts\":").is_err());
+        // baselines saved before they contained the year.
+        assert!(Baseline::parse("{\"parts\":[]}").is_err());
+    }
+
+    #[test]
+    fn test_read_other_year() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-baseline-test-{}.json", std::process::id()));
+        Baseline::save(&path, 2021, &[]).unwrap();
+        assert!(Baseline::read(&path, 2021).is_ok());
+        assert_eq!(
+            Baseline::read(&path, 2022),
+            Err(String::from(
+                "the baseline was saved for 2021, not for 2022"
+            ))
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::parse(&report_to_json(
+            2022,
+            &[PartResult::answered(
+                1,
+                1,
+                Some("1"),
+                Duration::from_micros(100),
+            )],
+        ))
+        .unwrap();
+        assert!(baseline
+            .compare(
+                2021,
+                &[PartResult::answered(
+                    1,
+                    1,
+                    Some("1"),
+                    Duration::from_micros(125)
+                )]
+            )
+            .is_empty());
+        let comparisons = baseline.compare(
+            2022,
+            &[
+                PartResult::answered(1, 1, Some("1"), Duration::from_micros(125)),
+                PartResult::answered(1, 2, Some("2"), Duration::from_micros(10)),
+                PartResult::answered(2, 1, None, Duration::from_micros(10)),
+            ],
+        );
+
+        assert_eq!(comparisons.len(), 1);
+        let comparison = &comparisons[0];
+        assert_eq!(comparison.percent(), 25_f64);
+        assert!(comparison.is_slowdown(10_f64));
+        assert!(!comparison.is_slowdown(30_f64));
+        assert_eq!(
+            comparison.format(10_f64),
+            "Day 01 Part 1: 100.00µs → 125.00µs (+25.00µs, +25.0%) ❌ slower"
+        );
+    }
+
+    #[test]
+    fn test_format_speedup() {
+        let comparison = Comparison {
+            day: 3,
+            part: 2,
+            baseline: Duration::from_micros(200),
+            current: Duration::from_micros(150),
+        };
+        assert_eq!(
+            comparison.format(10_f64),
+            "Day 03 Part 2: 200.00µs → 150.00µs (-50.00µs, -25.0%)"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+use crate::PartResult;
+
+/// time spent running a part before samples are recorded.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+/// time after which sampling stops once `MIN_SAMPLES` have been recorded.
+const MEASUREMENT_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 10_000;
+
+/// Timing statistics over repeated runs of a part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// Combines the stats of several parts into stats for running all of them once.
+    /// Min, median and mean are summed, deviations are combined as independent variances.
+    pub fn total(stats: &[Stats]) -> Stats {
+        let variance: f64 = stats
+            .iter()
+            .map(|stats| stats.stddev.as_secs_f64().powi(2))
+            .sum();
+
+        Stats {
+            samples: stats.iter().map(|stats| stats.samples).sum(),
+            min: stats.iter().map(|stats| stats.min).sum(),
+            median: stats.iter().map(|stats| stats.median).sum(),
+            mean: stats.iter().map(|stats| stats.mean).sum(),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Warms up and then runs a part repeatedly until enough samples have been collected.
+/// The returned result reports the median as its elapsed time.
+pub fn bench(run: impl Fn() -> PartResult) -> PartResult {
+    let warmup = Instant::now();
+    let mut result = run();
+    while warmup.elapsed() < WARMUP_TIME {
+        result = run();
+    }
+
+    let mut samples: Vec<Duration> = vec![];
+    let timer = Instant::now();
+    while samples.len() < MAX_SAMPLES
+        && (samples.len() < MIN_SAMPLES || timer.elapsed() < MEASUREMENT_TIME)
+    {
+        result = run();
+        samples.push(result.elapsed);
+    }
+
+    let stats = Stats::from_samples(&samples);
+    PartResult {
+        elapsed: stats.median,
+        stats: Some(stats),
+        ..result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|n| Duration::from_micros(*n)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 8, 6]));
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        // sample standard deviation of [2, 4, 6, 8] is sqrt(20 / 3).
+        assert_eq!(stats.stddev.as_nanos(), 2582);
+
+        let stats = Stats::from_samples(&micros(&[3, 1, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2));
+
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats.median, Duration::from_micros(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stats_total() {
+        let a = Stats::from_samples(&micros(&[1, 3]));
+        let b = Stats::from_samples(&micros(&[10, 10, 10]));
+        let total = Stats::total(&[a, b]);
+        assert_eq!(total.samples, 5);
+        assert_eq!(total.min, Duration::from_micros(11));
+        assert_eq!(total.median, Duration::from_micros(12));
+        assert_eq!(total.mean, Duration::from_micros(12));
+        assert_eq!(total.stddev, a.stddev);
+    }
+
+    #[test]
+    fn test_bench() {
+        let result = bench(|| PartResult::answered(1, 1, Some("1"), Duration::from_nanos(10)));
+        let stats = result.stats.unwrap();
+        assert!(stats.samples >= MIN_SAMPLES && stats.samples <= MAX_SAMPLES);
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(result.elapsed, Duration::from_nanos(10));
+    }
+}
@@ -2,104 +2,107 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::cache::{self, Cache};
+use advent_of_code::config::Config;
+use advent_of_code::puzzle;
+use std::path::Path;
+use std::time::SystemTime;
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<i32>,
+    force: bool,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
+        force: args.contains("--force"),
+        refresh: args.contains("--refresh"),
         year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
+        Err(e) => exit_with_error(&format!("Failed to process arguments: {}", e)),
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let config = Config::load();
+    let now = SystemTime::now();
+    let year = args.year.unwrap_or_else(|| config.year());
+    let input_dir = config.dir("inputs", year);
+    let input_path = input_dir.join(format!("{:02}.txt", args.day));
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    // never ask the server for puzzles that cannot have an input yet.
+    if let Err(e) = aoc::check_unlocked(year, args.day, now) {
+        exit_with_error(&format!("Refusing to download input: {}.", e));
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(&e));
+    let cache = Cache::new(Path::new(cache::DEFAULT_DIR));
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    let cached = if args.force {
+        None
+    } else {
+        cache.read_input(year, args.day, client.session())
+    };
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    let input = match cached {
+        Some(input) => {
+            println!(
+                "Using cached input for day {}, {} (pass --force to download it again).",
+                args.day, year
+            );
+            input
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+        None => {
+            println!("Downloading input for day {}, {}...", args.day, year);
+            let input = client
+                .get_input(year, args.day)
+                .unwrap_or_else(|e| exit_with_error(&format!("Failed to download input: {}", e)));
+            if let Err(e) = cache.write_input(year, args.day, client.session(), &input) {
+                eprintln!("could not write input to cache: {}", e);
+            }
+            input
         }
+    };
+
+    match fs::create_dir_all(&input_dir).and_then(|_| fs::write(&input_path, input)) {
+        Ok(_) => println!("Wrote input to \"{}\".", input_path.display()),
+        Err(e) => exit_with_error(&format!("could not write input file: {}", e)),
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+    // the description gains part two once part one is solved. It is only fetched again when asked
+    // for, so that downloading a cached input does not contact the server.
+    let has_description = puzzle::markdown_path(year, args.day).exists();
+    if !has_description || args.force || args.refresh {
+        println!("Downloading puzzle for day {}, {}...", args.day, year);
+        let saved = puzzle::download(&client, &cache, year, args.day)
+            .and_then(|page| puzzle::save_markdown(year, args.day, &page));
+        match saved {
+            Ok(path) => println!("Wrote puzzle description to \"{}\".", path.display()),
+            Err(e) => eprintln!("Failed to download puzzle description: {}", e),
         }
+    } else if !puzzle::is_complete(year, args.day) {
+        println!(
+            "The puzzle description does not contain part two yet, pass --refresh to download it again once part one is solved."
+        );
     }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
 }
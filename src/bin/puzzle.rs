@@ -0,0 +1,79 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::cache::{self, Cache};
+use advent_of_code::config::Config;
+use advent_of_code::puzzle;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    html: Option<PathBuf>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        force: args.contains("--force"),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        html: args.opt_value_from_str("--html")?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let config = Config::load();
+    let year = args.year.unwrap_or_else(|| config.year());
+    let page = match &args.html {
+        // convert a saved page without contacting the server.
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|e| {
+            exit_with_error(&format!("Could not read \"{}\": {}", path.display(), e))
+        }),
+        None => {
+            let now = SystemTime::now();
+            if let Err(e) = aoc::check_unlocked(year, args.day, now) {
+                exit_with_error(&format!("Refusing to download puzzle: {}.", e));
+            }
+            if puzzle::is_complete(year, args.day) && !args.force {
+                println!(
+                    "\"{}\" already contains both parts (pass --force to download it again).",
+                    puzzle::markdown_path(year, args.day).display()
+                );
+                return;
+            }
+
+            let client = Client::from_env().unwrap_or_else(|e| exit_with_error(&e));
+            let cache = Cache::new(Path::new(cache::DEFAULT_DIR));
+            println!("Downloading puzzle for day {}, {}...", args.day, year);
+            puzzle::download(&client, &cache, year, args.day)
+                .unwrap_or_else(|e| exit_with_error(&format!("Failed to download puzzle: {}", e)))
+        }
+    };
+
+    match puzzle::save_markdown(year, args.day, &page) {
+        Ok(path) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote puzzle description to \"{}\".",
+                path.display()
+            );
+        }
+        Err(e) => exit_with_error(&format!("Could not write puzzle description: {}", e)),
+    }
+}
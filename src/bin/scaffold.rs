@@ -2,113 +2,368 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::aoc;
+use advent_of_code::cache::{self, Cache};
+use advent_of_code::config::Config;
+use advent_of_code::puzzle;
+use advent_of_code::template::{self, AnswerType};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    html: Option<PathBuf>,
+    template: Option<PathBuf>,
+    answer_type: AnswerType,
+    force: Vec<Artifact>,
+    dry_run: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// A file that is created by the scaffold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Artifact {
+    Module,
+    Input,
+    Example,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+impl Artifact {
+    fn name(&self) -> &'static str {
+        match self {
+            Artifact::Module => "module",
+            Artifact::Input => "input",
+            Artifact::Example => "example",
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Parses the artifacts that `--force` may overwrite. Inputs are never overwritten.
+fn parse_force(s: &str) -> Result<Vec<Artifact>, String> {
+    s.split(',')
+        .map(|artifact| match artifact.trim() {
+            "module" => Ok(vec![Artifact::Module]),
+            "example" => Ok(vec![Artifact::Example]),
+            "all" => Ok(vec![Artifact::Module, Artifact::Example]),
+            other => Err(format!(
+                "cannot overwrite \"{}\", expected module, example or all",
+                other
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|artifacts| artifacts.concat())
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+/// What the scaffold does with a file.
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Create(String),
+    Overwrite(String),
+    Keep(Reason),
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+/// Why an existing file is kept.
+#[derive(Debug, PartialEq, Eq)]
+enum Reason {
+    /// `--force` was not passed for the file.
+    NotForced,
+    /// inputs are never overwritten.
+    Input,
+    /// `--force` was passed, but there is nothing to replace the file with, e.g. no puzzle page.
+    NoContents,
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        html: args.opt_value_from_str("--html")?,
+        template: args.opt_value_from_str("--template")?,
+        answer_type: args
+            .opt_value_from_str(["-t", "--type"])?
+            .unwrap_or(AnswerType::U32),
+        force: args
+            .opt_value_from_fn("--force", parse_force)?
+            .unwrap_or_default(),
+        dry_run: args.contains("--dry-run"),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Reads the puzzle page passed with `--html`, or the page that `cargo download` left in the cache.
+fn read_page(args: &Args, year: i32) -> Option<String> {
+    match &args.html {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(page) => Some(page),
+            Err(e) => {
+                eprintln!("Could not read \"{}\": {}", path.display(), e);
+                process::exit(1);
+            }
+        },
+        None => {
+            let session = aoc::read_session().ok()?;
+            Cache::new(Path::new(cache::DEFAULT_DIR)).read_page(year, args.day, &session)
+        }
+    }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Renders the expected example answer of a part for the generated tests.
+fn render_expected(part: u8, answer: &Option<String>, answer_type: AnswerType) -> String {
+    let Some(answer) = answer else {
+        return String::from("None");
+    };
+    answer_type.expression(answer).unwrap_or_else(|| {
+        println!(
+            "Example answer \"{}\" of part {} is not a `{}`, fill it into the test by hand.",
+            answer, part, answer_type
+        );
+        String::from("None")
+    })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Reads the template passed with `--template`, or `templates/day.rs.tmpl`.
+fn read_template(args: &Args) -> String {
+    let path = args
+        .template
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(template::DEFAULT_TEMPLATE));
+    fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Could not read template \"{}\": {}", path.display(), e);
+        process::exit(1);
+    })
+}
+
+/// Decides what to do with a file, so that existing work is only replaced when asked for.
+/// `contents` is `None` if there is nothing to fill the file with.
+fn plan(artifact: Artifact, path: &Path, contents: Option<String>, force: &[Artifact]) -> Action {
+    let existing = match fs::metadata(path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Action::Create(contents.unwrap_or_default()),
+    };
+    match (artifact, contents) {
+        (Artifact::Input, _) => Action::Keep(Reason::Input),
+        (_, Some(contents)) if force.contains(&artifact) => Action::Overwrite(contents),
+        (_, None) if force.contains(&artifact) => Action::Keep(Reason::NoContents),
+        (Artifact::Example, Some(example)) if existing == 0 => Action::Overwrite(example),
+        _ => Action::Keep(Reason::NotForced),
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
     };
+    let day = args.day;
+    let config = Config::load();
+    let year = args.year.unwrap_or_else(|| config.year());
+
+    let page = read_page(&args, year);
+    let [part_one_examples, part_two_examples] =
+        page.as_deref().map(puzzle::examples).unwrap_or_default();
+    let [part_one_expected, part_two_expected] = page
+        .as_deref()
+        .map(puzzle::example_answers)
+        .unwrap_or_default();
 
     let day_padded = format!("{:02}", day);
+    let title = page
+        .as_deref()
+        .and_then(puzzle::title)
+        .unwrap_or_else(|| format!("Day {}", day));
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    // render before creating any file, so a broken template leaves nothing behind.
+    let variables = [
+        ("day", day.to_string()),
+        ("day_padded", day_padded.clone()),
+        ("year", year.to_string()),
+        ("title", title),
+        ("answer_type", args.answer_type.to_string()),
+        (
+            "part_one_expected",
+            render_expected(1, &part_one_expected, args.answer_type),
+        ),
+        (
+            "part_two_expected",
+            render_expected(2, &part_two_expected, args.answer_type),
+        ),
+    ];
+    let module = template::render(&read_template(&args), &variables).unwrap_or_else(|e| {
+        eprintln!("Failed to render template: {}", e);
+        process::exit(1);
+    });
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
-    };
+    let year_dir = advent_of_code::year_dir(year);
+    let input_dir = config.dir("inputs", year);
+    let example_dir = config.dir("examples", year);
+    let mut files = vec![
+        (
+            Artifact::Module,
+            year_dir.join(format!("{}.rs", day_padded)),
+            Some(module),
+        ),
+        (
+            Artifact::Input,
+            input_dir.join(format!("{}.txt", day_padded)),
+            None,
+        ),
+        (
+            Artifact::Example,
+            example_dir.join(format!("{}.txt", day_padded)),
+            part_one_examples.into_iter().next(),
+        ),
+    ];
+    // part two only gets an example of its own if the puzzle has a new one, see `read_example`.
+    if let Some(example) = part_two_examples.into_iter().next() {
+        files.push((
+            Artifact::Example,
+            example_dir.join(format!("{}-2.txt", day_padded)),
+            Some(example),
+        ));
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+    if !args.dry_run {
+        for dir in [&year_dir, &input_dir, &example_dir] {
+            if let Err(e) = fs::create_dir_all(dir) {
+                eprintln!("Failed to create directory: {}", e);
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    for (artifact, path, contents) in files {
+        let (verb, done, contents) = match plan(artifact, &path, contents, &args.force) {
+            Action::Keep(reason) => {
+                let reason = match reason {
+                    Reason::NotForced => {
+                        format!("pass `--force {}` to overwrite it", artifact.name())
+                    }
+                    Reason::Input => String::from("inputs are never overwritten"),
+                    Reason::NoContents => {
+                        String::from("there is no puzzle page to replace it with")
+                    }
+                };
+                println!(
+                    "Kept existing {} file \"{}\" ({})",
+                    artifact.name(),
+                    path.display(),
+                    reason
+                );
+                continue;
+            }
+            Action::Create(contents) => ("create", "Created", contents),
+            Action::Overwrite(contents) => ("overwrite", "Overwrote", contents),
+        };
+        let description = if contents.is_empty() {
+            format!("empty {} file", artifact.name())
+        } else {
+            format!("{} file", artifact.name())
+        };
+
+        if args.dry_run {
+            println!("Would {} {} \"{}\"", verb, description, path.display());
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+        match fs::write(&path, contents) {
+            Ok(_) => println!("{} {} \"{}\"", done, description, path.display()),
+            Err(e) => {
+                eprintln!("Failed to write {} file: {}", artifact.name(), e);
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
+    if args.dry_run {
+        return;
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} {}` to run your solution.",
+        year, &day_padded
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_parse_force() {
+        assert_eq!(parse_force("module"), Ok(vec![Artifact::Module]));
+        assert_eq!(
+            parse_force("example, module"),
+            Ok(vec![Artifact::Example, Artifact::Module])
+        );
+        assert_eq!(
+            parse_force("all"),
+            Ok(vec![Artifact::Module, Artifact::Example])
+        );
+        assert_eq!(
+            parse_force("input"),
+            Err(String::from(
+                "cannot overwrite \"input\", expected module, example or all"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_plan() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let missing = dir.join("missing.txt");
+        let empty = dir.join("empty.txt");
+        let example = dir.join("example.txt");
+        fs::write(&empty, "").unwrap();
+        fs::write(&example, "1000\n2000\n").unwrap();
+        let contents = || Some(String::from("3000\n"));
+        let force = [Artifact::Module, Artifact::Example];
+
+        assert_eq!(
+            plan(Artifact::Example, &missing, contents(), &[]),
+            Action::Create(String::from("3000\n"))
+        );
+        assert_eq!(
+            plan(Artifact::Input, &missing, None, &[]),
+            Action::Create(String::new())
+        );
+
+        // existing files are kept unless forced, empty examples are filled in.
+        assert_eq!(
+            plan(Artifact::Example, &example, contents(), &[]),
+            Action::Keep(Reason::NotForced)
+        );
+        assert_eq!(
+            plan(Artifact::Example, &empty, contents(), &[]),
+            Action::Overwrite(String::from("3000\n"))
+        );
+        assert_eq!(
+            plan(Artifact::Module, &example, contents(), &force),
+            Action::Overwrite(String::from("3000\n"))
+        );
+        assert_eq!(
+            plan(Artifact::Input, &example, contents(), &force),
+            Action::Keep(Reason::Input)
+        );
+
+        // without a puzzle page, `--force` does not replace an example with nothing.
+        assert_eq!(
+            plan(Artifact::Example, &example, None, &force),
+            Action::Keep(Reason::NoContents)
+        );
+        assert_eq!(fs::read_to_string(&example).unwrap(), "1000\n2000\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
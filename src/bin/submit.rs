@@ -0,0 +1,176 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::config::Config;
+use advent_of_code::submission::{self, History, Outcome, Submission};
+use advent_of_code::verify;
+use std::path::Path;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// generated by `build.rs`: includes every solution in `src/y{year}/` and registers it in `solutions()`.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: Option<i32>,
+    force: bool,
+    history: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        force: args.contains("--force"),
+        history: args.contains("--history"),
+        day: args.free_from_str()?,
+        part: args.free_from_fn(|s| match s {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err(format!("\"{}\" is not a part, expected 1 or 2", s)),
+        })?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Runs a part of the day on its input to get the answer to submit.
+fn solve(year: i32, day: u8, part: u8) -> Result<String, String> {
+    let solution = solutions()
+        .into_iter()
+        .find(|solution| solution.year == year && solution.day == day)
+        .ok_or(format!(
+            "there is no solution for day {:02} in \"{}\"",
+            day,
+            advent_of_code::year_dir(year).display()
+        ))?;
+    let input = advent_of_code::try_read_file("inputs", year, day)
+        .map_err(|e| format!("could not open input file for day {:02}: {}", day, e))?;
+
+    let solver = if part == 1 {
+        solution.part_one
+    } else {
+        solution.part_two
+    };
+    let result = solver(&input);
+    advent_of_code::print_result(&result);
+
+    result
+        .answer
+        .ok_or(format!("part {} of day {:02} is not solved", part, day))
+}
+
+fn print_history(attempts: &[&Submission]) {
+    if attempts.is_empty() {
+        println!("No submissions yet.");
+    }
+    for attempt in attempts {
+        println!(
+            "{}  {:<12}  {}",
+            aoc::format_utc(attempt.submitted_at),
+            attempt.answer,
+            attempt.outcome.describe()
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let config = Config::load();
+    advent_of_code::set_color(config.color);
+    let now = SystemTime::now();
+    let year = args.year.unwrap_or_else(|| config.year());
+    if let Err(e) = aoc::check_unlocked(year, args.day, now) {
+        exit_with_error(&format!("Refusing to submit: {}.", e));
+    }
+
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(&e));
+    let log = Path::new(submission::DEFAULT_LOG);
+    let history = History::read(log, client.session()).unwrap_or_else(|e| {
+        exit_with_error(&format!(
+            "Could not read submissions from \"{}\": {}",
+            log.display(),
+            e
+        ))
+    });
+
+    if args.history {
+        print_history(&history.attempts(year, args.day, args.part));
+        return;
+    }
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => solve(year, args.day, args.part).unwrap_or_else(|e| exit_with_error(&e)),
+    };
+    let answer = answer.trim().to_string();
+    if answer.is_empty() {
+        exit_with_error("Refusing to submit an empty answer.");
+    }
+
+    let submitted_at = now
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    if !args.force {
+        if let Err(e) = history.check(year, args.day, args.part, &answer, submitted_at) {
+            exit_with_error(&format!(
+                "Refusing to submit: {}. Pass --force to submit anyway.",
+                e
+            ));
+        }
+    }
+
+    println!(
+        "Submitting \"{}\" for day {}, part {} of {}...",
+        answer, args.day, args.part, year
+    );
+    let response = client
+        .post_answer(year, args.day, args.part, &answer)
+        .unwrap_or_else(|e| exit_with_error(&format!("Failed to submit answer: {}", e)));
+    let outcome = submission::parse_response(&response);
+
+    println!("---");
+    println!("🎄 {}", outcome.describe());
+
+    let submitted = Submission {
+        year,
+        day: args.day,
+        part: args.part,
+        answer: answer.clone(),
+        outcome: outcome.clone(),
+        submitted_at,
+    };
+    if let Err(e) = submission::record(log, &submitted, client.session()) {
+        eprintln!(
+            "could not record submission in \"{}\": {}",
+            log.display(),
+            e
+        );
+    }
+
+    match outcome {
+        Outcome::Correct => match verify::save_answer(year, args.day, args.part, &answer) {
+            Ok(_) => println!(
+                "Stored the answer in \"{}/answers/{:02}.txt\".",
+                advent_of_code::year_dir(year).display(),
+                args.day
+            ),
+            Err(e) => eprintln!("could not store the answer: {}", e),
+        },
+        Outcome::AlreadySolved => {}
+        _ => process::exit(1),
+    }
+}
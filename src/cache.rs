@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// directory for downloaded inputs, relative to the repository root.
+pub const DEFAULT_DIR: &str = ".aoc/cache";
+
+/// Short, stable fingerprint of a session cookie, so that inputs of different accounts
+/// are cached separately without writing the cookie itself to disk.
+pub fn session_hash(session: &str) -> String {
+    Sha256::digest(session.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Downloaded puzzle inputs, keyed by year, day and session.
+/// Inputs never change once a puzzle is unlocked, so cached inputs are never fetched again.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Cache {
+        Cache {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    fn path(&self, year: i32, day: u8, session: &str, extension: &str) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!(
+            "{:02}-{}.{}",
+            day,
+            session_hash(session),
+            extension
+        ))
+    }
+
+    fn read(&self, path: PathBuf) -> Option<String> {
+        fs::read_to_string(path).ok()
+    }
+
+    fn write(&self, path: PathBuf, contents: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+
+    /// Location of an input in the cache, e.g. `.aoc/cache/2022/01-3f2a...txt`.
+    pub fn input_path(&self, year: i32, day: u8, session: &str) -> PathBuf {
+        self.path(year, day, session, "txt")
+    }
+
+    /// Returns the cached input, or `None` if it was not downloaded yet.
+    pub fn read_input(&self, year: i32, day: u8, session: &str) -> Option<String> {
+        self.read(self.input_path(year, day, session))
+    }
+
+    pub fn write_input(&self, year: i32, day: u8, session: &str, input: &str) -> io::Result<()> {
+        self.write(self.input_path(year, day, session), input)
+    }
+
+    /// Location of the last downloaded puzzle page in the cache, e.g. `.aoc/cache/2022/01-3f2a...html`.
+    /// Unlike inputs, pages change when part one is solved, so they are only kept for offline use.
+    pub fn page_path(&self, year: i32, day: u8, session: &str) -> PathBuf {
+        self.path(year, day, session, "html")
+    }
+
+    pub fn read_page(&self, year: i32, day: u8, session: &str) -> Option<String> {
+        self.read(self.page_path(year, day, session))
+    }
+
+    pub fn write_page(&self, year: i32, day: u8, session: &str, page: &str) -> io::Result<()> {
+        self.write(self.page_path(year, day, session), page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_session_hash() {
+        assert_eq!(session_hash("abc123"), "6ca13d52ca70c883");
+        assert_ne!(session_hash("abc123"), session_hash("abc124"));
+    }
+
+    #[test]
+    fn test_read_write_input() {
+        let dir = env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+
+        assert_eq!(cache.read_input(2022, 1, "abc123"), None);
+        cache.write_input(2022, 1, "abc123", "1000\n").unwrap();
+        assert_eq!(
+            cache.read_input(2022, 1, "abc123"),
+            Some(String::from("1000\n"))
+        );
+        assert_eq!(cache.read_input(2022, 1, "other"), None);
+        assert_eq!(cache.read_input(2021, 1, "abc123"), None);
+        assert_eq!(
+            cache.input_path(2022, 1, "abc123"),
+            dir.join("2022").join("01-6ca13d52ca70c883.txt")
+        );
+
+        assert_eq!(cache.read_page(2022, 1, "abc123"), None);
+        cache
+            .write_page(2022, 1, "abc123", "<main></main>")
+            .unwrap();
+        assert_eq!(
+            cache.read_page(2022, 1, "abc123"),
+            Some(String::from("<main></main>"))
+        );
+        assert_eq!(
+            cache.read_input(2022, 1, "abc123"),
+            Some(String::from("1000\n"))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
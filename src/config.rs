@@ -0,0 +1,200 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    aoc,
+    output::Format,
+    settings::{self, Setting, Value},
+    year_dir,
+};
+
+/// project settings file, relative to the repository root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Project settings from `aoc.toml`. Command line flags take precedence over all settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// `year`: year used when none is passed. Defaults to the most recent event.
+    pub year: Option<i32>,
+    /// `inputs_dir`: directory of the inputs of a year, `{year}` is replaced with the year.
+    pub inputs_dir: String,
+    /// `examples_dir`: directory of the examples of a year, `{year}` is replaced with the year.
+    pub examples_dir: String,
+    /// `format`: output format used when `--format` is not passed.
+    pub format: Format,
+    /// `timeout`: seconds after which a part is stopped when `--timeout` is not passed.
+    pub timeout: Option<Duration>,
+    /// `color`: use ANSI colors in text output.
+    pub color: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            year: None,
+            inputs_dir: String::from("src/y{year}/inputs"),
+            examples_dir: String::from("src/y{year}/examples"),
+            format: Format::Text,
+            timeout: None,
+            color: true,
+        }
+    }
+}
+
+impl Config {
+    /// Parses a settings file. Unknown keys are rejected to catch typos.
+    pub fn parse(contents: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        for Setting { line, key, value } in settings::parse(contents)? {
+            let error = |message: String| format!("line {}: {}", line, message);
+
+            match (key.as_str(), value) {
+                ("year", Value::Integer(year)) if year >= 2015 => config.year = Some(year as i32),
+                ("inputs_dir", Value::String(dir)) => config.inputs_dir = dir,
+                ("examples_dir", Value::String(dir)) => config.examples_dir = dir,
+                ("format", Value::String(format)) => {
+                    config.format = format.parse().map_err(error)?
+                }
+                ("timeout", Value::Integer(seconds)) if seconds > 0 => {
+                    config.timeout = Some(Duration::from_secs(seconds as u64))
+                }
+                ("timeout", Value::Float(seconds)) if seconds.is_finite() && seconds > 0_f64 => {
+                    config.timeout = Some(Duration::from_secs_f64(seconds))
+                }
+                ("color", Value::Boolean(color)) => config.color = color,
+                (
+                    "year" | "inputs_dir" | "examples_dir" | "format" | "timeout" | "color",
+                    value,
+                ) => return Err(error(format!("invalid value {:?} for \"{}\"", value, key))),
+                _ => return Err(error(format!("unknown setting \"{}\"", key))),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a settings file. A missing file results in the default settings.
+    pub fn read(path: &Path) -> Result<Config, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Config::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{}", e)),
+        }
+    }
+
+    /// Reads `aoc.toml` from a directory. Binaries built with the `embed` feature fall back to the
+    /// settings of the build if the directory has none, so they can be run outside of the repository.
+    pub fn read_from(dir: &Path) -> Result<Config, String> {
+        let path = dir.join(CONFIG_FILE);
+        #[cfg(feature = "embed")]
+        if !path.exists() {
+            if let Some(contents) = crate::embedded::CONFIG {
+                return Config::parse(contents);
+            }
+        }
+        Config::read(&path)
+    }
+
+    /// Reads `aoc.toml` from the working directory, exiting the process if it is invalid.
+    pub fn load() -> Config {
+        Config::read_from(Path::new(".")).unwrap_or_else(|e| {
+            eprintln!("Invalid \"{}\": {}", CONFIG_FILE, e);
+            process::exit(1);
+        })
+    }
+
+    /// Year used when none is passed: `year`, or the most recent event.
+    pub fn year(&self) -> i32 {
+        self.year
+            .unwrap_or_else(|| aoc::latest_event_year(SystemTime::now()))
+    }
+
+    /// Directory of a folder of a year, e.g. `src/y2022/inputs`.
+    /// `inputs` and `examples` follow `inputs_dir` and `examples_dir`, other folders live in `src/y{year}/`.
+    pub fn dir(&self, folder: &str, year: i32) -> PathBuf {
+        let template = match folder {
+            "inputs" => &self.inputs_dir,
+            "examples" => &self.examples_dir,
+            _ => return year_dir(year).join(folder),
+        };
+        PathBuf::from(template.replace("{year}", &year.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+# shared settings
+year = 2021
+inputs_dir = "data/{year}/inputs" # kept out of src
+format = "ndjson"
+timeout = 2.5
+color = false
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                year: Some(2021),
+                inputs_dir: String::from("data/{year}/inputs"),
+                examples_dir: String::from("src/y{year}/examples"),
+                format: Format::Ndjson,
+                timeout: Some(Duration::from_millis(2500)),
+                color: false,
+            }
+        );
+        assert_eq!(config.year(), 2021);
+        assert_eq!(config.dir("inputs", 2021), Path::new("data/2021/inputs"));
+        assert_eq!(
+            config.dir("examples", 2021),
+            Path::new("src/y2021/examples")
+        );
+        assert_eq!(config.dir("answers", 2021), Path::new("src/y2021/answers"));
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(
+            Config::parse("timeout = 10").unwrap().timeout,
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Config::parse("year = 2022\ncolour = false").unwrap_err(),
+            "line 2: unknown setting \"colour\""
+        );
+        assert_eq!(
+            Config::parse("format = \"yaml\"").unwrap_err(),
+            "line 1: unknown format \"yaml\", expected one of: text, json, ndjson"
+        );
+        assert!(Config::parse("year = \"2022\"").is_err());
+        assert!(Config::parse("year = 1999").is_err());
+        assert!(Config::parse("timeout = 0").is_err());
+        assert!(Config::parse("inputs_dir = \"src").is_err());
+        assert!(Config::parse("color").is_err());
+    }
+
+    #[test]
+    fn test_read_missing() {
+        assert_eq!(
+            Config::read(Path::new("does-not-exist.toml")).unwrap(),
+            Config::default()
+        );
+    }
+}
@@ -0,0 +1,213 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+/// Selection of the year, days and parts for the aggregate runner.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Filter {
+    /// `[year]` or `--year <year>`: year to run. Defaults to `year` in `aoc.toml`.
+    pub year: Option<i32>,
+    /// `[day]` or `--days <list>`: days to run, e.g. `3-7,10`. Defaults to all days.
+    pub days: Vec<u8>,
+    /// `--part <1|2>`: only run one part of each day.
+    pub part: Option<u8>,
+    /// `--solved-only`: skip days without a solution in `src/y{year}/`.
+    pub solved_only: bool,
+    /// `--input <path>`: read the input of a single day from a file, or from stdin for `-`.
+    pub input: Option<PathBuf>,
+}
+
+/// Parses a comma-separated list of days and day ranges, e.g. `3-7,10`.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| -> Result<u8, String> {
+        match day.trim().parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("\"{}\" is not a day between 1 and 25", day.trim())),
+        }
+    };
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(format!("\"{}\" is not an ascending range", item.trim()));
+                }
+                days.extend(from..=to);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses the positional arguments `[year] [day]`, e.g. `2022 5`, `2022` or `5`.
+pub fn parse_positional(values: &[String]) -> Result<(Option<i32>, Option<u8>), String> {
+    let parse_year = |year: &str| match year.parse::<i32>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("\"{}\" is not a year of Advent of Code", year)),
+    };
+
+    match values {
+        [] => Ok((None, None)),
+        [value] if value.len() <= 2 => Ok((None, Some(parse_days(value)?[0]))),
+        [year] => Ok((Some(parse_year(year)?), None)),
+        [year, day] if !day.contains([',', '-']) => {
+            Ok((Some(parse_year(year)?), Some(parse_days(day)?[0])))
+        }
+        _ => Err(format!(
+            "expected \"[year] [day]\", got \"{}\"",
+            values.join(" ")
+        )),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", s)),
+    }
+}
+
+impl Filter {
+    /// Parses the filter flags and `[year] [day]`. Takes the arguments that are left after all other
+    /// flags were taken from them, so that unknown flags can be rejected instead of being ignored.
+    pub fn parse(mut args: pico_args::Arguments) -> Result<Filter, pico_args::Error> {
+        let error = |cause: String| pico_args::Error::ArgumentParsingFailed { cause };
+
+        let year = args.opt_value_from_str(["-y", "--year"])?;
+        let days = args.opt_value_from_fn("--days", parse_days)?;
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+        let solved_only = args.contains("--solved-only");
+        let input: Option<PathBuf> = args.opt_value_from_str("--input")?;
+
+        let positional: Vec<String> = args
+            .finish()
+            .into_iter()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        if let Some(flag) = positional.iter().find(|arg| arg.starts_with('-')) {
+            return Err(error(format!("unknown flag \"{}\"", flag)));
+        }
+        let (positional_year, positional_day) = parse_positional(&positional).map_err(error)?;
+
+        Ok(Filter {
+            year: match (positional_year, year) {
+                (Some(_), Some(_)) => {
+                    return Err(error(String::from("pass either [year] or --year")))
+                }
+                (positional_year, year) => positional_year.or(year),
+            },
+            days: match (positional_day, days) {
+                (Some(_), Some(_)) => {
+                    return Err(error(String::from("pass either [day] or --days")))
+                }
+                (Some(day), None) => vec![day],
+                (None, days) => days.unwrap_or_else(|| (1..=25).collect()),
+            },
+            part,
+            solved_only,
+            input,
+        })
+        .and_then(|filter| match filter.days.len() {
+            1 => Ok(filter),
+            _ if filter.input.is_none() => Ok(filter),
+            _ => Err(error(String::from("--input needs a single day"))),
+        })
+    }
+
+    /// `true` if the part should be run.
+    pub fn includes_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3-7,10"), Ok(vec![3, 4, 5, 6, 7, 10]));
+        assert_eq!(parse_days("10, 1 ,1-2"), Ok(vec![1, 2, 10]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("7-3").is_err());
+        assert!(parse_days("3-").is_err());
+        assert!(parse_days("").is_err());
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let args = |args: &[&str]| {
+            pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect())
+        };
+
+        let filter = Filter::parse(args(&["--days", "3-4", "--part", "2", "--solved-only"]));
+        assert_eq!(
+            filter.unwrap(),
+            Filter {
+                year: None,
+                days: vec![3, 4],
+                part: Some(2),
+                solved_only: true,
+                input: None
+            }
+        );
+
+        let filter = Filter::parse(args(&[])).unwrap();
+        assert_eq!(filter.days.len(), 25);
+        assert!(filter.includes_part(1) && filter.includes_part(2));
+        assert!(!filter.solved_only);
+
+        assert!(Filter::parse(args(&["--part", "3"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_positional() {
+        let args = |args: &[&str]| {
+            Filter::parse(pico_args::Arguments::from_vec(
+                args.iter().map(OsString::from).collect(),
+            ))
+        };
+
+        let filter = args(&["2022", "05"]).unwrap();
+        assert_eq!((filter.year, filter.days), (Some(2022), vec![5]));
+
+        let filter = args(&["05"]).unwrap();
+        assert_eq!((filter.year, filter.days), (None, vec![5]));
+
+        let filter = args(&["2021"]).unwrap();
+        assert_eq!((filter.year, filter.days.len()), (Some(2021), 25));
+
+        let filter = args(&["--year", "2021"]).unwrap();
+        assert_eq!((filter.year, filter.days.len()), (Some(2021), 25));
+
+        // positionals can follow flags.
+        let filter = args(&["--solved-only", "05"]).unwrap();
+        assert_eq!((filter.year, filter.days), (None, vec![5]));
+
+        assert!(args(&["2022", "--part2"]).is_err());
+
+        assert!(args(&["2022", "--year", "2021"]).is_err());
+        assert!(args(&["5", "--days", "1-3"]).is_err());
+        assert!(args(&["2022", "26"]).is_err());
+        assert!(args(&["1999"]).is_err());
+        assert!(args(&["2022", "5", "6"]).is_err());
+
+        let filter = args(&["2022", "5", "--input", "-"]).unwrap();
+        assert_eq!(filter.input, Some(PathBuf::from("-")));
+        let filter = args(&["--days", "5", "--input", "friend.txt"]).unwrap();
+        assert_eq!(filter.input, Some(PathBuf::from("friend.txt")));
+        assert!(args(&["2022", "--input", "friend.txt"]).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Piece of an HTML document, as produced by [`tokenize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// an opening tag with its lowercase name and raw attributes, e.g. `a` and ` href="/2022"`.
+    Open(String, &'a str),
+    /// a closing tag with its lowercase name.
+    Close(String),
+    /// text between tags, with entities still encoded.
+    Text(&'a str),
+}
+
+/// Splits an HTML document into tags and text. Comments and doctypes are skipped.
+/// This is just enough HTML for the pages of the Advent of Code website.
+pub fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        if rest[start..].starts_with("<!--") {
+            rest = rest[start..]
+                .find("-->")
+                .map_or("", |end| &rest[start + end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = rest[start + 1..end].trim_end_matches('/');
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !tag.starts_with('!') {
+            let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            tokens.push(Token::Open(
+                tag[..name_end].to_lowercase(),
+                &tag[name_end..],
+            ));
+        }
+        rest = &rest[end + 1..];
+    }
+
+    tokens
+}
+
+/// Returns the value of an attribute from the raw attributes of an [`Token::Open`] tag.
+pub fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &attributes[start..];
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next(),
+        _ => value.split_whitespace().next(),
+    }
+}
+
+/// Decodes the entities used on the Advent of Code website.
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Returns the inner HTML of every `<article>` of a page.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content) = rest[start..].find('>').map(|end| start + end + 1) else {
+            break;
+        };
+        let end = rest[content..]
+            .find("</article>")
+            .map_or(rest.len(), |end| content + end);
+        articles.push(&rest[content..end]);
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// Removes all tags and decodes entities.
+pub fn strip_tags(html: &str) -> String {
+    let text: String = tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+    decode_entities(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("<!DOCTYPE html><!-- x --><P class=\"a\">1 &lt; 2<br/></p>"),
+            vec![
+                Token::Open(String::from("p"), " class=\"a\""),
+                Token::Text("1 &lt; 2"),
+                Token::Open(String::from("br"), ""),
+                Token::Close(String::from("p")),
+            ]
+        );
+        assert_eq!(tokenize("text"), vec![Token::Text("text")]);
+    }
+
+    #[test]
+    fn test_attribute() {
+        let attributes = " href=\"/2022/day/1/input\" target=_blank title='x y'";
+        assert_eq!(attribute(attributes, "href"), Some("/2022/day/1/input"));
+        assert_eq!(attribute(attributes, "target"), Some("_blank"));
+        assert_eq!(attribute(attributes, "title"), Some("x y"));
+        assert_eq!(attribute(attributes, "id"), None);
+    }
+
+    #[test]
+    fn test_articles() {
+        let html = "<main><article class=\"day-desc\"><p>one</p></article><p>x</p><article><p>two</p></article></main>";
+        assert_eq!(articles(html), vec!["<p>one</p>", "<p>two</p>"]);
+        assert!(articles("<main></main>").is_empty());
+    }
+
+    #[test]
+    fn test_strip_tags() {
+        assert_eq!(
+            strip_tags("<p>Santa&#39;s <em>&quot;elves&quot;</em> &amp; co</p>"),
+            "Santa's \"elves\" & co"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{error, fmt};
+
+pub use regex::Regex;
+
+/// Normalizes an input before it is passed to a solution: CRLF line endings become LF, and
+/// trailing empty lines are replaced with exactly one newline. Spaces within lines are kept,
+/// as some puzzles (e.g. drawings of crates) depend on them.
+pub fn normalize(input: &str) -> String {
+    let input = input.replace("\r\n", "\n");
+    let content = input.trim_end_matches('\n');
+    if content.is_empty() {
+        return String::new();
+    }
+    format!("{}\n", content)
+}
+
+/// A line of an input that does not have the expected format.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidLine {
+    /// line number, starting at 1.
+    pub line: usize,
+    pub content: String,
+    pub pattern: String,
+}
+
+impl fmt::Display for InvalidLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {:?} does not match `{}`",
+            self.line, self.content, self.pattern
+        )
+    }
+}
+
+/// Checks that every line of an input matches a pattern, e.g. `^\d*$` for lists of numbers.
+/// Returns the first line that does not match.
+pub fn validate(input: &str, pattern: &Regex) -> Result<(), InvalidLine> {
+    match input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| !pattern.is_match(line))
+    {
+        Some((i, line)) => Err(InvalidLine {
+            line: i + 1,
+            content: line.to_string(),
+            pattern: pattern.as_str().to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Error of a solver that could not make sense of its input, see [`crate::Answer`].
+/// Carries the position in the input so the runner can point at the offending line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// line number, starting at 1.
+    pub line: Option<usize>,
+    /// column number, starting at 1.
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> ParseError {
+        ParseError {
+            line: Some(line),
+            ..self
+        }
+    }
+
+    pub fn at_column(self, column: usize) -> ParseError {
+        ParseError {
+            column: Some(column),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<InvalidLine> for ParseError {
+    fn from(invalid: InvalidLine) -> ParseError {
+        ParseError::new(format!(
+            "{:?} does not match `{}`",
+            invalid.content, invalid.pattern
+        ))
+        .at_line(invalid.line)
+    }
+}
+
+/// Parses every line of an input, adding the line number to the first error.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse(line).map_err(|e| match e.line {
+                Some(_) => e,
+                None => e.at_line(i + 1),
+            })
+        })
+        .collect()
+}
+
+/// Declares the format of every line of an input at the start of a solver, e.g.
+/// `advent_of_code::validate_lines!(input, r"^\d*$")?;`. The pattern is compiled once.
+/// Evaluates to a [`ParseError`] for the offending line, which the runner reports for the part
+/// instead of a wrong answer.
+#[macro_export]
+macro_rules! validate_lines {
+    ($input:expr, $pattern:literal) => {{
+        static PATTERN: std::sync::OnceLock<$crate::input::Regex> = std::sync::OnceLock::new();
+        let pattern = PATTERN.get_or_init(|| $crate::input::Regex::new($pattern).unwrap());
+        $crate::input::validate($input, pattern).map_err($crate::input::ParseError::from)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("1000\r\n2000\r\n\r\n4000"),
+            "1000\n2000\n\n4000\n"
+        );
+        assert_eq!(normalize("1000\n\n\n"), "1000\n");
+        assert_eq!(
+            normalize("    [D]    \n[N] [C]    \n"),
+            "    [D]    \n[N] [C]    \n"
+        );
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn test_validate() {
+        let pattern = Regex::new(r"^\d*$").unwrap();
+        assert_eq!(validate("1000\n\n2000\n", &pattern), Ok(()));
+        assert_eq!(
+            validate("1000\n\n20OO\n", &pattern),
+            Err(InvalidLine {
+                line: 3,
+                content: String::from("20OO"),
+                pattern: String::from(r"^\d*$"),
+            })
+        );
+        assert_eq!(
+            validate("20OO", &pattern).unwrap_err().to_string(),
+            r#"line 1: "20OO" does not match `^\d*$`"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+        assert_eq!(
+            ParseError::new("expected `move N from A to B`")
+                .at_line(12)
+                .to_string(),
+            "line 12: expected `move N from A to B`"
+        );
+        assert_eq!(
+            ParseError::new("unknown pick \"W\"")
+                .at_line(3)
+                .at_column(3)
+                .to_string(),
+            "line 3, column 3: unknown pick \"W\""
+        );
+
+        let pattern = Regex::new(r"^\d*$").unwrap();
+        assert_eq!(
+            ParseError::from(validate("1\nx", &pattern).unwrap_err()).to_string(),
+            r#"line 2: "x" does not match `^\d*$`"#
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| {
+            line.parse::<u32>()
+                .map_err(|_| ParseError::new(format!("{:?} is not a number", line)))
+        };
+        assert_eq!(parse_lines("1\n2\n", parse), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_lines("1\nx\n3", parse).unwrap_err().to_string(),
+            r#"line 2: "x" is not a number"#
+        );
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        assert_eq!(validate_lines!("1\n\n2\n", r"^\d*$"), Ok(()));
+        assert_eq!(
+            validate_lines!("1\nx\n", r"^\d*$").unwrap_err(),
+            ParseError::new(r#""x" does not match `^\d*$`"#).at_line(2)
+        );
+    }
+}
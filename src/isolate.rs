@@ -0,0 +1,143 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    any::Any,
+    panic,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Failure, PartResult, Solver};
+
+/// Takes `--timeout <seconds>` from the arguments, defaulting to `default`.
+pub fn timeout_from_args(
+    args: &mut pico_args::Arguments,
+    default: Option<Duration>,
+) -> Result<Option<Duration>, pico_args::Error> {
+    Ok(args
+        .opt_value_from_fn("--timeout", parse_timeout)?
+        .or(default))
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(seconds) if seconds.is_finite() && seconds > 0_f64 => {
+            Ok(Duration::from_secs_f64(seconds))
+        }
+        _ => Err(format!("\"{}\" is not a positive number of seconds", s)),
+    }
+}
+
+/// Extracts the message that was passed to `panic!`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic payload")
+    }
+}
+
+/// Runs a part on its own thread, so that a panic or a part that exceeds `timeout`
+/// is reported as a [`Failure`] instead of taking down or blocking the runner.
+/// A timed out part keeps running in the background until the process exits.
+pub fn run(
+    solver: Solver,
+    day: u8,
+    part: u8,
+    input: Arc<str>,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let (sender, receiver) = mpsc::channel();
+
+    let timer = Instant::now();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(|| solver(&input));
+        // the receiver is gone if the part timed out.
+        let _ = sender.send(result);
+    });
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|_| ()),
+        None => receiver.recv().map_err(|_| ()),
+    };
+
+    let failed = |failure: Failure, elapsed: Duration| PartResult {
+        elapsed,
+        failure: Some(failure),
+        ..PartResult::new(day, part)
+    };
+
+    match received {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => failed(
+            Failure::Panicked(panic_message(payload.as_ref())),
+            timer.elapsed(),
+        ),
+        Err(_) => failed(Failure::TimedOut, timeout.unwrap_or(timer.elapsed())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_part;
+
+    fn parse(input: &str) -> PartResult {
+        run_part(1, 1, |input: &str| input.parse::<u32>().ok(), input)
+    }
+
+    fn panics(input: &str) -> PartResult {
+        run_part(
+            1,
+            2,
+            |_: &str| -> Option<u32> { panic!("bad input: {}", input) },
+            input,
+        )
+    }
+
+    fn hangs(input: &str) -> PartResult {
+        thread::sleep(Duration::from_secs(5));
+        parse(input)
+    }
+
+    #[test]
+    fn test_run_solved() {
+        let result = run(parse, 1, 1, Arc::from("42"), None);
+        assert_eq!(result.answer, Some(String::from("42")));
+        assert_eq!(result.failure, None);
+        assert_eq!(result.status(), "solved");
+    }
+
+    #[test]
+    fn test_run_panicked() {
+        let result = run(panics, 1, 2, Arc::from("x"), None);
+        assert_eq!(
+            result.failure,
+            Some(Failure::Panicked(String::from("bad input: x")))
+        );
+        assert_eq!(result.status(), "panicked");
+        assert_eq!((result.day, result.part), (1, 2));
+    }
+
+    #[test]
+    fn test_run_timed_out() {
+        let timeout = Duration::from_millis(50);
+        let result = run(hangs, 1, 1, Arc::from("1"), Some(timeout));
+        assert_eq!(result.failure, Some(Failure::TimedOut));
+        assert_eq!(result.elapsed, timeout);
+        assert_eq!(result.status(), "timed_out");
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
@@ -4,122 +4,449 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
+pub mod aoc;
+pub mod baseline;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod filter;
 pub mod helpers;
+pub mod html;
+pub mod input;
+pub mod isolate;
+pub mod output;
+pub mod parallel;
+pub mod puzzle;
+pub mod readme;
+pub mod settings;
+pub mod submission;
+pub mod template;
+pub mod verify;
+
+#[cfg(feature = "embed")]
+mod embedded {
+    // generated by `build.rs`: includes every input and example with `include_str!`.
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+static COLOR: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables ANSI colors in text output, see `color` in `aoc.toml`.
+pub fn set_color(enabled: bool) {
+    COLOR.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns an ANSI code, or nothing if colors are disabled.
+pub fn style(code: &'static str) -> &'static str {
+    if COLOR.load(Ordering::Relaxed) {
+        code
+    } else {
+        ""
+    }
+}
+
+/// The outcome of running one part of a solution.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    /// time spent in the solver, excluding overhead like file reads.
+    pub elapsed: Duration,
+    /// the stored answer for this part, see [`verify::check`].
+    pub expected: Option<String>,
+    /// timing statistics if the part was run with `--bench`.
+    pub stats: Option<bench::Stats>,
+    /// set if the solver did not return, see [`isolate::run`].
+    pub failure: Option<Failure>,
+}
+
+/// Reason why a solver did not return an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    TimedOut,
+    Panicked(String),
+    /// the solver returned an error, e.g. an [`input::ParseError`].
+    Error(String),
+    /// the day has a solution, but its input could not be read.
+    MissingInput(String),
+}
+
+impl PartResult {
+    /// Result of a part that has not been run yet, see [`PartResult::status`].
+    pub fn new(day: u8, part: u8) -> PartResult {
+        PartResult {
+            day,
+            part,
+            ..PartResult::default()
+        }
+    }
+
+    /// Machine-readable status: `solved`, `unsolved`, `timed_out`, `panicked`, `error` or `missing_input`.
+    pub fn status(&self) -> &'static str {
+        match (&self.failure, &self.answer) {
+            (Some(Failure::TimedOut), _) => "timed_out",
+            (Some(Failure::Panicked(_)), _) => "panicked",
+            (Some(Failure::Error(_)), _) => "error",
+            (Some(Failure::MissingInput(_)), _) => "missing_input",
+            (None, Some(_)) => "solved",
+            (None, None) => "unsolved",
+        }
+    }
+
+    /// `None` if there is no stored answer to compare against.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| self.answer.as_ref() == Some(expected))
+    }
+}
+
+/// A solver that has been erased to produce a [`PartResult`].
+pub type Solver = fn(&str) -> PartResult;
+
+/// Entry of the solution registry that the aggregate runner (`cargo all`) calls in-process.
+pub struct Solution {
+    pub year: i32,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+/// Registers the `part_one` and `part_two` functions of a solution module.
+/// The registry is generated from the year directories in `src/` by `build.rs`.
 #[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+macro_rules! solution {
+    ($year:expr, $day:expr, $module:ident) => {
+        advent_of_code::Solution {
+            year: $year,
+            day: $day,
+            part_one: |input| advent_of_code::run_part($day, 1, $module::part_one, input),
+            part_two: |input| advent_of_code::run_part($day, 2, $module::part_two, input),
         }
+    };
+}
+
+/// Return type of a solver: `Option<T>`, or `Result<Option<T>, E>` for solvers that report
+/// invalid input as an error, usually an [`input::ParseError`].
+pub trait Answer {
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display, E: Display> Answer for Result<Option<T>, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map(|answer| answer.map(|answer| answer.to_string()))
+            .map_err(|e| e.to_string())
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Runs a single part and measures the time spent in the solver.
+pub fn run_part<R: Answer>(
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&str) -> R,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let answer = func(input);
+    let elapsed = timer.elapsed();
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    let (answer, failure) = match answer.into_answer() {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(Failure::Error(e))),
+    };
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    PartResult {
+        answer,
+        elapsed,
+        failure,
+        ..PartResult::new(day, part)
+    }
+}
+
+/// Renders a result as human-readable, colored text.
+pub fn format_result(result: &PartResult) -> String {
+    let header = format!(
+        "🎄 {}Part {}{} 🎄",
+        style(ANSI_BOLD),
+        result.part,
+        style(ANSI_RESET)
+    );
+    let verdict = match (result.is_correct(), &result.expected) {
+        (Some(true), _) => String::from(" ✅"),
+        (Some(false), Some(expected)) => format!(" ❌ expected {}", expected),
+        _ => String::new(),
+    };
+    let timing = match &result.stats {
+        Some(stats) => format_stats(stats),
+        None => format!("elapsed: {:.2?}", result.elapsed),
+    };
+    match (&result.failure, &result.answer) {
+        (Some(Failure::TimedOut), _) => format!(
+            "{}\ntimed out after {:.2?}.{}",
+            header, result.elapsed, verdict
+        ),
+        (Some(Failure::Panicked(message)), _) => {
+            format!("{}\npanicked: {}{}", header, message, verdict)
+        }
+        (Some(Failure::Error(message)), _) => {
+            format!("{}\nerror: {}{}", header, message, verdict)
+        }
+        (Some(Failure::MissingInput(message)), _) => {
+            format!("{}\nmissing input: {}{}", header, message, verdict)
+        }
+        (None, Some(answer)) => format!(
+            "{}\n{}{} {}({}){}",
+            header,
+            answer,
+            verdict,
+            style(ANSI_ITALIC),
+            timing,
+            style(ANSI_RESET)
+        ),
+        (None, None) => format!("{}\nnot solved.{}", header, verdict),
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Renders benchmark statistics as human-readable text.
+pub fn format_stats(stats: &bench::Stats) -> String {
+    format!(
+        "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, samples: {}",
+        stats.median, stats.mean, stats.stddev, stats.min, stats.samples
+    )
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+pub fn print_result(result: &PartResult) {
+    println!("{}", format_result(result));
+}
+
+/// Directory that holds the solutions and data of a year, e.g. `src/y2022`.
+pub fn year_dir(year: i32) -> PathBuf {
+    Path::new("src").join(format!("y{}", year))
+}
+
+pub fn read_file(folder: &str, year: i32, day: u8) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+/// Reads the file of a day from a folder of its year, e.g. `src/y2022/inputs/01.txt`.
+/// The folders of inputs and examples can be moved in `aoc.toml`, see [`config::Config::dir`].
+/// All files are normalized with [`input::normalize`].
+pub fn try_read_file(folder: &str, year: i32, day: u8) -> io::Result<String> {
+    read_year_file(folder, year, &format!("{:02}.txt", day))
+}
+
+fn read_year_file(folder: &str, year: i32, file_name: &str) -> io::Result<String> {
+    // files that did not exist at build time are still read from disk.
+    #[cfg(feature = "embed")]
+    if let Some(contents) = embedded::file(folder, year, file_name) {
+        return Ok(input::normalize(contents));
+    }
+
+    let cwd = env::current_dir()?;
+    let config = config::Config::read_from(&cwd)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    fs::read_to_string(cwd.join(config.dir(folder, year)).join(file_name))
+        .map(|contents| input::normalize(&contents))
+}
+
+/// Reads an input from a file, or from stdin if `path` is `-`. See `--input` in [`filter::Filter`].
+pub fn read_input_from(path: &Path) -> io::Result<String> {
+    let contents = if path == Path::new("-") {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(input::normalize(&contents))
+}
+
+pub fn read_example(year: i32, day: u8, part_or_name: impl Display) -> String {
+    try_read_example(year, day, part_or_name).expect("could not open example file")
+}
+
+/// Reads a named example of a day, e.g. `src/y2022/examples/09-2.txt` for `try_read_example(2022, 9, 2)`.
+/// Parts without an example of their own (`1` and `2`) fall back to the example of the day, `09.txt`.
+pub fn try_read_example(year: i32, day: u8, part_or_name: impl Display) -> io::Result<String> {
+    let name = part_or_name.to_string();
+    match read_year_file("examples", year, &format!("{:02}-{}.txt", day, name)) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && (name == "1" || name == "2") => {
+            try_read_file("examples", year, day)
         }
-    })
+        result => result,
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
 #[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+impl PartResult {
+    /// Result of a part that returned `answer` after `elapsed`, shared by the tests of all modules.
+    pub(crate) fn answered(
+        day: u8,
+        part: u8,
+        answer: Option<&str>,
+        elapsed: Duration,
+    ) -> PartResult {
+        PartResult {
+            answer: answer.map(String::from),
+            elapsed,
+            ..PartResult::new(day, part)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn solver(input: &str) -> Option<u32> {
+        input.parse().ok()
+    }
+
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_run_part() {
+        let result = run_part(3, 2, solver, "42");
+        assert_eq!(result.day, 3);
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer, Some(String::from("42")));
+
+        assert_eq!(run_part(3, 2, solver, "").answer, None);
+
+        let fallible = |input: &str| -> Result<Option<u32>, input::ParseError> {
+            input
+                .parse()
+                .map(Some)
+                .map_err(|_| input::ParseError::new("expected a number").at_line(1))
+        };
+        let result = run_part(3, 2, fallible, "42");
+        assert_eq!(
+            (result.answer, result.failure),
+            (Some(String::from("42")), None)
+        );
+        let result = run_part(3, 2, fallible, "x");
+        assert_eq!(result.answer, None);
+        assert_eq!(
+            result.failure,
+            Some(Failure::Error(String::from("line 1: expected a number")))
+        );
+        assert_eq!(result.status(), "error");
+    }
+
+    #[test]
+    fn test_read_example() {
+        let shared = read_file("examples", 2022, 9);
+        assert_eq!(read_example(2022, 9, 1), shared);
+        assert_ne!(read_example(2022, 9, 2), shared);
+        assert_eq!(read_example(2022, 1, 2), read_file("examples", 2022, 1));
+        assert!(try_read_example(2022, 1, "larger").is_err());
+    }
+
+    #[test]
+    fn test_format_result() {
+        let mut result = PartResult::answered(1, 1, Some("24000"), Duration::from_micros(755));
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\n24000 {}(elapsed: 755.00µs){}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
+        );
+
+        result.expected = Some(String::from("24000"));
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\n24000 ✅ {}(elapsed: 755.00µs){}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
+        );
+
+        result.answer = None;
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\nnot solved. ❌ expected 24000",
+                ANSI_BOLD, ANSI_RESET
+            )
+        );
+
+        result.expected = None;
+        assert_eq!(
+            format_result(&result),
+            format!("🎄 {}Part 1{} 🎄\nnot solved.", ANSI_BOLD, ANSI_RESET)
+        );
+
+        result.failure = Some(Failure::TimedOut);
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\ntimed out after 755.00µs.",
+                ANSI_BOLD, ANSI_RESET
+            )
+        );
+
+        result.failure = Some(Failure::Panicked(String::from("cannot get char code")));
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\npanicked: cannot get char code",
+                ANSI_BOLD, ANSI_RESET
+            )
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        result.failure = Some(Failure::Error(String::from("line 2: expected a number")));
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\nerror: line 2: expected a number",
+                ANSI_BOLD, ANSI_RESET
+            )
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        result.failure = Some(Failure::MissingInput(String::from(
+            "No such file or directory (os error 2)",
+        )));
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\nmissing input: No such file or directory (os error 2)",
+                ANSI_BOLD, ANSI_RESET
+            )
         );
+        assert_eq!(result.status(), "missing_input");
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        result.failure = None;
+        result.answer = Some(String::from("24000"));
+        result.stats = Some(bench::Stats {
+            samples: 100,
+            min: Duration::from_micros(700),
+            median: Duration::from_micros(755),
+            mean: Duration::from_micros(760),
+            stddev: Duration::from_micros(12),
+        });
+        assert_eq!(
+            format_result(&result),
+            format!(
+                "🎄 {}Part 1{} 🎄\n24000 {}(median: 755.00µs, mean: 760.00µs ± 12.00µs, min: 700.00µs, samples: 100){}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
         );
     }
 }
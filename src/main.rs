@@ -2,45 +2,339 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::baseline::{self, Baseline};
+use advent_of_code::config::Config;
+use advent_of_code::filter::Filter;
+use advent_of_code::output::{self, Format};
+use advent_of_code::{bench, isolate, parallel, readme, verify};
+use advent_of_code::{style, Failure, PartResult, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+// generated by `build.rs`: includes every solution in `src/y{year}/` and registers it in `solutions()`.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// Flags of the runner. All flags are taken from the same arguments, so that [`Filter::parse`]
+/// can reject the ones that are left as unknown.
+struct Args {
+    format: Format,
+    is_bench: bool,
+    is_serial: bool,
+    update_readme: bool,
+    timeout: Option<Duration>,
+    baseline: baseline::Args,
+    filter: Filter,
+}
+
+fn parse_args(config: &Config) -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        format: Format::from_args(&mut args, config.format)?,
+        is_bench: args.contains("--bench"),
+        is_serial: args.contains("--serial"),
+        update_readme: args.contains("--update-readme"),
+        timeout: isolate::timeout_from_args(&mut args, config.timeout)?,
+        baseline: baseline::Args::parse(&mut args)?,
+        // parsed last, `[year] [day]` are taken from the arguments that are left.
+        filter: Filter::parse(args)?,
+    })
+}
+
+/// Outcome of running a single day, which is printed in order of the days.
+enum DayRun {
+    NotSolved(Vec<PartResult>),
+    Solved(Vec<PartResult>),
+}
+
+fn find_solution(solutions: &[Solution], day: u8) -> Option<&Solution> {
+    solutions.iter().find(|solution| solution.day == day)
+}
+
+fn run_day(
+    year: i32,
+    day: u8,
+    solution: Option<&Solution>,
+    filter: &Filter,
+    input: Option<&Arc<str>>,
+    is_bench: bool,
+    timeout: Option<Duration>,
+) -> DayRun {
+    let parts = [1, 2]
+        .into_iter()
+        .filter(|&part| filter.includes_part(part));
+
+    let solution = match solution {
+        Some(solution) => solution,
+        None => return DayRun::NotSolved(parts.map(|part| PartResult::new(day, part)).collect()),
+    };
 
-            if is_empty {
-                0_f64
+    let input: Arc<str> = match input {
+        Some(input) => Arc::clone(input),
+        None => match advent_of_code::try_read_file("inputs", year, day) {
+            Ok(input) => Arc::from(input),
+            Err(e) => {
+                let failed = |part| PartResult {
+                    failure: Some(Failure::MissingInput(e.to_string())),
+                    ..PartResult::new(day, part)
+                };
+                return DayRun::Solved(parts.map(failed).collect());
+            }
+        },
+    };
+
+    let mut results: Vec<PartResult> = parts
+        .map(|part| {
+            let solver = if part == 1 {
+                solution.part_one
+            } else {
+                solution.part_two
+            };
+            // only benchmark parts that finished on their own, without panicking.
+            let result = isolate::run(solver, day, part, Arc::clone(&input), timeout);
+            if is_bench && result.failure.is_none() {
+                bench::bench(|| solver(&input))
             } else {
-                advent_of_code::parse_exec_time(&output)
+                result
             }
         })
-        .sum();
+        .collect();
+    // stored answers belong to our own input, not to the one passed with `--input`.
+    if filter.input.is_none() {
+        verify::check(year, &mut results);
+    }
+
+    DayRun::Solved(results)
+}
+
+/// Number of days to run at once.
+fn jobs(is_bench: bool, is_serial: bool, update_readme: bool, baseline: &baseline::Args) -> usize {
+    // timings that are benchmarked, saved or compared are always measured serially, parallel runs would skew them.
+    let is_timed =
+        is_bench || baseline.save.is_some() || baseline.compare.is_some() || update_readme;
+    if is_timed || is_serial {
+        1
+    } else {
+        parallel::available_jobs()
+    }
+}
+
+fn emit(format: Format, result: &PartResult) {
+    match format {
+        Format::Text => advent_of_code::print_result(result),
+        Format::Ndjson => println!("{}", output::part_to_json(result)),
+        Format::Json => {}
+    }
+}
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+fn main() {
+    let config = Config::load();
+    advent_of_code::set_color(config.color);
+    let Args {
+        format,
+        is_bench,
+        is_serial,
+        update_readme,
+        timeout,
+        baseline: baseline_args,
+        filter,
+    } = parse_args(&config).unwrap_or_else(|e| {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    });
+
+    let year = filter.year.unwrap_or_else(|| config.year());
+    // read the baseline up front so a missing file does not waste a full run.
+    let baseline = baseline_args.compare.as_ref().map(|path| {
+        Baseline::read(path, year).unwrap_or_else(|e| {
+            eprintln!("Could not read baseline \"{}\": {}", path.display(), e);
+            process::exit(1);
+        })
+    });
+    // read up front, stdin can only be read once.
+    let input: Option<Arc<str>> =
+        filter
+            .input
+            .as_ref()
+            .map(|path| match advent_of_code::read_input_from(path) {
+                Ok(input) => Arc::from(input),
+                Err(e) => {
+                    eprintln!("Could not read input \"{}\": {}", path.display(), e);
+                    process::exit(1);
+                }
+            });
+    let solutions: Vec<Solution> = solutions()
+        .into_iter()
+        .filter(|solution| solution.year == year)
+        .collect();
+    let mut results: Vec<PartResult> = vec![];
+
+    let days: Vec<u8> = filter
+        .days
+        .iter()
+        .copied()
+        .filter(|&day| !filter.solved_only || find_solution(&solutions, day).is_some())
+        .collect();
+
+    let jobs = jobs(is_bench, is_serial, update_readme, &baseline_args);
+
+    parallel::map_ordered(
+        &days,
+        jobs,
+        |&day| {
+            let run = run_day(
+                year,
+                day,
+                find_solution(&solutions, day),
+                &filter,
+                input.as_ref(),
+                is_bench,
+                timeout,
+            );
+            (day, run)
+        },
+        |(day, run)| {
+            if format == Format::Text {
+                println!("----------");
+                println!(
+                    "{}| Day {:02} |{}",
+                    style(ANSI_BOLD),
+                    day,
+                    style(ANSI_RESET)
+                );
+                println!("----------");
+            }
+
+            match run {
+                DayRun::NotSolved(day_results) => {
+                    if format == Format::Text {
+                        println!("Not solved.");
+                    }
+                    for result in day_results {
+                        if format != Format::Text {
+                            emit(format, &result);
+                        }
+                        results.push(result);
+                    }
+                }
+                DayRun::Solved(day_results) => {
+                    for result in day_results {
+                        emit(format, &result);
+                        results.push(result);
+                    }
+                }
+            }
+        },
     );
+
+    match format {
+        Format::Text => match output::total_stats(&results) {
+            Some(stats) => println!(
+                "{}Total:{} {}{}{}",
+                style(ANSI_BOLD),
+                style(ANSI_RESET),
+                style(ANSI_ITALIC),
+                advent_of_code::format_stats(&stats),
+                style(ANSI_RESET)
+            ),
+            None => {
+                let total: Duration = results.iter().map(|result| result.elapsed).sum();
+                println!(
+                    "{}Total:{} {}{:.2}ms{}",
+                    style(ANSI_BOLD),
+                    style(ANSI_RESET),
+                    style(ANSI_ITALIC),
+                    total.as_secs_f64() * 1000_f64,
+                    style(ANSI_RESET)
+                );
+            }
+        },
+        Format::Json => println!("{}", output::report_to_json(year, &results)),
+        Format::Ndjson => {}
+    }
+
+    if let Some(path) = &baseline_args.save {
+        match Baseline::save(path, year, &results) {
+            Ok(_) => eprintln!("Saved baseline to \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Could not save baseline \"{}\": {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if update_readme {
+        match readme::update(Path::new("README.md"), year, &results) {
+            Ok(_) => eprintln!("Updated benchmarks in \"README.md\"."),
+            Err(e) => {
+                eprintln!("Could not update \"README.md\": {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let is_slower = baseline.is_some_and(|baseline| {
+        baseline::print_comparison(&baseline.compare(year, &results), baseline_args.threshold)
+    });
+
+    if results.iter().any(|result| result.failure.is_some()) {
+        eprintln!(
+            "❌ Some parts returned an error, panicked, timed out or are missing their input."
+        );
+        process::exit(1);
+    }
+
+    if verify::has_regression(&results) {
+        eprintln!(
+            "❌ Some answers do not match the stored answers in \"{}\".",
+            advent_of_code::year_dir(year).join("answers").display()
+        );
+        process::exit(1);
+    }
+
+    if is_slower {
+        eprintln!("❌ Some parts are slower than the baseline allows.");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn baseline_args(args: &[&str]) -> baseline::Args {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        baseline::Args::parse(&mut args).unwrap()
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(
+            jobs(false, false, false, &baseline_args(&[])),
+            parallel::available_jobs()
+        );
+        assert_eq!(jobs(true, false, false, &baseline_args(&[])), 1);
+        assert_eq!(jobs(false, true, false, &baseline_args(&[])), 1);
+        assert_eq!(jobs(false, false, true, &baseline_args(&[])), 1);
+        assert_eq!(
+            jobs(
+                false,
+                false,
+                false,
+                &baseline_args(&["--save-baseline", "base.json"])
+            ),
+            1
+        );
+        assert_eq!(
+            jobs(
+                false,
+                false,
+                false,
+                &baseline_args(&["--baseline", "base.json"])
+            ),
+            1
+        );
+    }
 }
@@ -0,0 +1,211 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{bench::Stats, Failure, PartResult};
+
+/// Output format of the runner (`cargo solve` and `cargo all`), selected with `--format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// colored, human-readable text.
+    Text,
+    /// a single JSON document containing all parts and the total time.
+    Json,
+    /// one JSON object per part and line, printed as soon as the part completes.
+    Ndjson,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "ndjson" => Ok(Format::Ndjson),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, ndjson",
+                s
+            )),
+        }
+    }
+}
+
+impl Format {
+    /// Takes the `--format` flag from the arguments, defaulting to `default`.
+    pub fn from_args(
+        args: &mut pico_args::Arguments,
+        default: Format,
+    ) -> Result<Format, pico_args::Error> {
+        Ok(args.opt_value_from_str("--format")?.unwrap_or(default))
+    }
+}
+
+/// Encodes `s` as a JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn stats_to_json(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            stats.samples,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => String::from("null"),
+    }
+}
+
+/// Renders a result as a single-line JSON object.
+pub fn part_to_json(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => json_string(answer),
+        None => String::from("null"),
+    };
+    let error = match &result.failure {
+        Some(
+            Failure::Panicked(message) | Failure::Error(message) | Failure::MissingInput(message),
+        ) => json_string(message),
+        _ => String::from("null"),
+    };
+    let expected = match &result.expected {
+        Some(expected) => json_string(expected),
+        None => String::from("null"),
+    };
+    let correct = match result.is_correct() {
+        Some(correct) => correct.to_string(),
+        None => String::from("null"),
+    };
+    format!(
+        "{{\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{},\"elapsed_ns\":{},\"expected\":{},\"correct\":{},\"bench\":{},\"error\":{}}}",
+        result.day,
+        result.part,
+        result.status(),
+        answer,
+        result.elapsed.as_nanos(),
+        expected,
+        correct,
+        stats_to_json(&result.stats),
+        error
+    )
+}
+
+/// Combines the benchmark stats of all benchmarked results.
+pub fn total_stats(results: &[PartResult]) -> Option<Stats> {
+    let stats: Vec<Stats> = results.iter().filter_map(|result| result.stats).collect();
+    if stats.is_empty() {
+        None
+    } else {
+        Some(Stats::total(&stats))
+    }
+}
+
+/// Renders the results of a year as a JSON document including the total time.
+pub fn report_to_json(year: i32, results: &[PartResult]) -> String {
+    let parts: Vec<_> = results.iter().map(part_to_json).collect();
+    let total: Duration = results.iter().map(|result| result.elapsed).sum();
+    format!(
+        "{{\"year\":{},\"parts\":[{}],\"total_elapsed_ns\":{},\"total_bench\":{}}}",
+        year,
+        parts.join(","),
+        total.as_nanos(),
+        stats_to_json(&total_stats(results))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("ndjson".parse(), Ok(Format::Ndjson));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_part_to_json() {
+        assert_eq!(
+            part_to_json(&PartResult::answered(
+                5,
+                1,
+                Some("CMZ"),
+                Duration::from_nanos(1500)
+            )),
+            r#"{"day":5,"part":1,"status":"solved","answer":"CMZ","elapsed_ns":1500,"expected":null,"correct":null,"bench":null,"error":null}"#
+        );
+        assert_eq!(
+            part_to_json(&PartResult::answered(5, 2, None, Duration::from_nanos(20))),
+            r#"{"day":5,"part":2,"status":"unsolved","answer":null,"elapsed_ns":20,"expected":null,"correct":null,"bench":null,"error":null}"#
+        );
+        assert_eq!(
+            part_to_json(&PartResult::answered(
+                5,
+                2,
+                Some("#.\"\n.#"),
+                Duration::from_nanos(0)
+            )),
+            r##"{"day":5,"part":2,"status":"solved","answer":"#.\"\n.#","elapsed_ns":0,"expected":null,"correct":null,"bench":null,"error":null}"##
+        );
+
+        let mut panicked = PartResult::answered(5, 2, None, Duration::from_nanos(30));
+        panicked.failure = Some(Failure::Panicked(String::from("cannot convert \"W\"")));
+        assert_eq!(
+            part_to_json(&panicked),
+            r#"{"day":5,"part":2,"status":"panicked","answer":null,"elapsed_ns":30,"expected":null,"correct":null,"bench":null,"error":"cannot convert \"W\""}"#
+        );
+
+        let mut incorrect = PartResult::answered(5, 1, Some("CMZ"), Duration::from_nanos(1500));
+        incorrect.expected = Some(String::from("MCD"));
+        assert_eq!(
+            part_to_json(&incorrect),
+            r#"{"day":5,"part":1,"status":"solved","answer":"CMZ","elapsed_ns":1500,"expected":"MCD","correct":false,"bench":null,"error":null}"#
+        );
+    }
+
+    #[test]
+    fn test_stats_to_json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(20)]);
+        assert_eq!(
+            stats_to_json(&Some(stats)),
+            r#"{"samples":2,"min_ns":10,"median_ns":15,"mean_ns":15,"stddev_ns":7}"#
+        );
+        assert_eq!(stats_to_json(&None), "null");
+    }
+
+    #[test]
+    fn test_report_to_json() {
+        assert_eq!(
+            report_to_json(
+                2022,
+                &[
+                    PartResult::answered(5, 1, Some("1"), Duration::from_nanos(10)),
+                    PartResult::answered(5, 2, None, Duration::from_nanos(5))
+                ]
+            ),
+            r#"{"year":2022,"parts":[{"day":5,"part":1,"status":"solved","answer":"1","elapsed_ns":10,"expected":null,"correct":null,"bench":null,"error":null},{"day":5,"part":2,"status":"unsolved","answer":null,"elapsed_ns":5,"expected":null,"correct":null,"bench":null,"error":null}],"total_elapsed_ns":15,"total_bench":null}"#
+        );
+    }
+}
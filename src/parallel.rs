@@ -0,0 +1,92 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Number of worker threads to use when running in parallel.
+pub fn available_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Applies `f` to every item on up to `jobs` threads.
+/// `on_result` is called on the current thread in the order of `items`,
+/// as soon as all results before it are available.
+pub fn map_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+    mut on_result: impl FnMut(R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 || items.len() <= 1 {
+        items.iter().for_each(|item| on_result(f(item)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                match items.get(index) {
+                    Some(item) => {
+                        if sender.send((index, f(item))).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_result = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_result) {
+                on_result(result);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_ordered() {
+        let items: Vec<u64> = (0..20).collect();
+        for jobs in [1, 4] {
+            let mut results = vec![];
+            map_ordered(
+                &items,
+                jobs,
+                |n| {
+                    // finish later items first to check the ordering.
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * 2
+                },
+                |result| results.push(result),
+            );
+            assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,365 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, path::PathBuf};
+
+use crate::{
+    aoc::{Client, DEFAULT_BASE_URL},
+    cache::Cache,
+    html::{self, Token},
+    year_dir,
+};
+
+/// heading of the second part of a puzzle, present on the page once part one is solved.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Location of the puzzle description of a day: `src/y{year}/puzzles/{day:02}.md`.
+pub fn markdown_path(year: i32, day: u8) -> PathBuf {
+    year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+/// `true` if the saved description of a day already contains both parts, so fetching it again would not change it.
+pub fn is_complete(year: i32, day: u8) -> bool {
+    fs::read_to_string(markdown_path(year, day))
+        .is_ok_and(|markdown| markdown.contains(PART_TWO_HEADING))
+}
+
+/// Ensures that `out` ends with an empty line, so the next block starts a new paragraph.
+fn start_block(out: &mut String) {
+    if out.is_empty() {
+        return;
+    }
+    while !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+/// Converts the HTML of a puzzle description to markdown.
+fn article_to_markdown(article: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in html::tokenize(article) {
+        match token {
+            Token::Text(text) => {
+                let text = html::decode_entities(text);
+                if in_pre {
+                    out.push_str(&text);
+                    continue;
+                }
+                let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.starts_with(char::is_whitespace) && !collapsed.is_empty() {
+                    collapsed.insert(0, ' ');
+                }
+                if text.ends_with(char::is_whitespace) && !collapsed.ends_with(' ') {
+                    collapsed.push(' ');
+                }
+                if out.is_empty() || out.ends_with([' ', '\n']) {
+                    collapsed = collapsed.trim_start().to_string();
+                }
+                out.push_str(&collapsed);
+            }
+            Token::Open(name, attributes) => match name.as_str() {
+                "h1" | "h2" | "h3" => {
+                    start_block(&mut out);
+                    out.push_str("## ");
+                }
+                "p" | "ul" => start_block(&mut out),
+                "li" => {
+                    if !out.is_empty() && !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("- ");
+                }
+                "pre" => {
+                    start_block(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                "code" if !in_pre => {
+                    out.push('`');
+                    in_code = true;
+                }
+                "em" if !in_pre && !in_code => out.push('*'),
+                "a" => {
+                    let href = html::attribute(attributes, "href").unwrap_or_default();
+                    let href = match href.strip_prefix('/') {
+                        Some(path) => format!("{}/{}", DEFAULT_BASE_URL, path),
+                        None => href.to_string(),
+                    };
+                    links.push(href);
+                    out.push('[');
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h1" | "h2" | "h3" | "p" | "ul" => start_block(&mut out),
+                "li" => out.push('\n'),
+                "pre" => {
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```");
+                    start_block(&mut out);
+                    in_pre = false;
+                }
+                "code" if !in_pre => {
+                    out.push('`');
+                    in_code = false;
+                }
+                "em" if !in_pre && !in_code => out.push('*'),
+                "a" => {
+                    out.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    format!("{}\n", out.trim_end())
+}
+
+/// Converts the `<article>` sections of a puzzle page to markdown.
+/// Returns `None` if the page does not contain a puzzle description.
+pub fn to_markdown(page: &str) -> Option<String> {
+    let articles = html::articles(page);
+    if articles.is_empty() {
+        return None;
+    }
+    Some(
+        articles
+            .into_iter()
+            .map(article_to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Extracts the title of a puzzle from the heading of its first part, e.g. `Calorie Counting`
+/// for `--- Day 1: Calorie Counting ---`.
+pub fn title(page: &str) -> Option<String> {
+    let article = html::articles(page).into_iter().next()?;
+    let start = article.find("<h2")?;
+    let end = start + article[start..].find("</h2>")?;
+    let heading = html::strip_tags(&article[start..end]);
+    let (_, title) = heading.split_once(':')?;
+    let title = title.trim().trim_end_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Extracts the example inputs of each part of a puzzle page: the first `<pre><code>` block,
+/// and every block that directly follows a paragraph containing "For example".
+/// Examples of part two that repeat an example of part one are left out.
+pub fn examples(page: &str) -> [Vec<String>; 2] {
+    let mut examples: [Vec<String>; 2] = Default::default();
+    let mut paragraph = String::new();
+    let mut block: Option<String> = None;
+    let mut is_first = true;
+
+    for (part, article) in html::articles(page).into_iter().take(2).enumerate() {
+        for token in html::tokenize(article) {
+            match (token, &mut block) {
+                (Token::Open(name, _), None) if name == "pre" => block = Some(String::new()),
+                (Token::Open(name, _), None) if name == "p" => paragraph.clear(),
+                (Token::Close(name), Some(_)) if name == "pre" => {
+                    let example = html::decode_entities(&block.take().unwrap_or_default());
+                    let is_new = !examples.iter().flatten().any(|known| *known == example);
+                    if is_new && (is_first || paragraph.to_lowercase().contains("for example")) {
+                        examples[part].push(example);
+                    }
+                    is_first = false;
+                    paragraph.clear();
+                }
+                (Token::Text(text), Some(block)) => block.push_str(text),
+                (Token::Text(text), None) => paragraph.push_str(text),
+                _ => {}
+            }
+        }
+    }
+
+    examples
+}
+
+/// Returns the last `<code><em>` value of an article, which holds the answer for the example.
+fn last_emphasized_code(article: &str) -> Option<String> {
+    let mut last = None;
+    let mut in_code = false;
+    let mut value: Option<String> = None;
+
+    for token in html::tokenize(article) {
+        match token {
+            Token::Open(name, _) if name == "code" => in_code = true,
+            Token::Close(name) if name == "code" => in_code = false,
+            Token::Open(name, _) if name == "em" && in_code => value = Some(String::new()),
+            Token::Close(name) if name == "em" => {
+                if let Some(value) = value.take() {
+                    last = Some(html::decode_entities(value.trim()));
+                }
+            }
+            Token::Text(text) => {
+                if let Some(value) = &mut value {
+                    value.push_str(text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    last.filter(|value| !value.is_empty())
+}
+
+/// Answers for the example of each part, taken from the last `<code><em>` value of the part's description.
+pub fn example_answers(page: &str) -> [Option<String>; 2] {
+    let articles = html::articles(page);
+    [
+        articles
+            .first()
+            .and_then(|article| last_emphasized_code(article)),
+        articles
+            .get(1)
+            .and_then(|article| last_emphasized_code(article)),
+    ]
+}
+
+/// Downloads the puzzle page of a day and keeps a copy in the cache.
+pub fn download(client: &Client, cache: &Cache, year: i32, day: u8) -> Result<String, String> {
+    let page = client.get_puzzle(year, day)?;
+    cache
+        .write_page(year, day, client.session(), &page)
+        .map_err(|e| format!("could not write the puzzle page to the cache: {}", e))?;
+    Ok(page)
+}
+
+/// Converts a puzzle page and writes it to `src/y{year}/puzzles/{day:02}.md`.
+pub fn save_markdown(year: i32, day: u8, page: &str) -> Result<PathBuf, String> {
+    let markdown = to_markdown(page).ok_or("the page does not contain a puzzle description")?;
+    let path = markdown_path(year, day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}", e))?;
+    }
+    fs::write(&path, markdown).map_err(|e| format!("{}", e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>Santa's reindeer typically eat regular reindeer food, but they need a lot of <a href="/2018/day/25">magical energy</a> to deliver presents.</p>
+<p>For example, suppose the Elves finish writing their items' <em>Calories</em> into a list:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>1000</code> Calories.</li>
+<li>The second Elf is carrying <code>4000</code> Calories.</li>
+</ul>
+<p>In this example, the Elf carrying the most Calories has a total of <code><em>4000</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves &amp; their total.</p>
+<pre><code>1 &lt; 2</code></pre>
+<p>For example, in a larger list:</p>
+<pre><code>5000
+<em>6000</em>
+</code></pre>
+<p>The total is <code><em>7000</em></code>, not <em>6000</em>.</p>
+</article>
+<p>To begin, <a href="1/input" target="_blank">get your puzzle input</a>.</p>
+</main>
+</body>
+</html>"##;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE).unwrap(),
+            "## --- Day 1: Calorie Counting ---
+
+Santa's reindeer typically eat regular reindeer food, but they need a lot of [magical energy](https://adventofcode.com/2018/day/25) to deliver presents.
+
+For example, suppose the Elves finish writing their items' *Calories* into a list:
+
+```
+1000
+2000
+
+4000
+```
+
+- The first Elf is carrying `1000` Calories.
+- The second Elf is carrying `4000` Calories.
+
+In this example, the Elf carrying the most Calories has a total of `4000`.
+
+## --- Part Two ---
+
+Find the top *three* Elves & their total.
+
+```
+1 < 2
+```
+
+For example, in a larger list:
+
+```
+5000
+6000
+```
+
+The total is `7000`, not *6000*.
+"
+        );
+        assert!(to_markdown(PAGE).unwrap().contains(PART_TWO_HEADING));
+        assert_eq!(to_markdown("<main>Please log in.</main>"), None);
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(title(PAGE), Some(String::from("Calorie Counting")));
+        assert_eq!(title("<main>Please log in.</main>"), None);
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(PAGE),
+            [
+                vec![String::from("1000\n2000\n\n4000\n")],
+                vec![String::from("5000\n6000\n")]
+            ]
+        );
+        assert_eq!(
+            examples("<main>Please log in.</main>"),
+            [Vec::<String>::new(), vec![]]
+        );
+
+        let repeated = PAGE.replace("5000\n<em>6000</em>\n", "1000\n2000\n\n4000\n");
+        assert_eq!(examples(&repeated)[1], Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PAGE),
+            [Some(String::from("4000")), Some(String::from("7000"))]
+        );
+        let part_one = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        assert_eq!(
+            example_answers(part_one),
+            [Some(String::from("4000")), None]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, path::Path, time::Duration};
+
+use crate::PartResult;
+
+/// start and end of the section in `README.md` that `--update-readme` rewrites.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+fn format_cell(result: Option<&PartResult>) -> String {
+    match result {
+        Some(result) if result.answer.is_some() => format!("`{:.2?}`", result.elapsed),
+        _ => String::from("-"),
+    }
+}
+
+/// Renders a markdown table with the timing of every part of the solved days of a year.
+pub fn render_table(year: i32, results: &[PartResult]) -> String {
+    let mut days: Vec<u8> = results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .map(|result| result.day)
+        .collect();
+    days.dedup();
+
+    let mut table = String::from("## Benchmarks\n\n");
+    table.push_str("| Day | Part 1 | Part 2 |\n");
+    table.push_str("| :---: | :---: | :---: |\n");
+
+    for day in days {
+        let part = |part: u8| {
+            results
+                .iter()
+                .find(|result| result.day == day && result.part == part)
+        };
+        table.push_str(&format!(
+            "| [Day {}](./src/y{}/{:02}.rs) | {} | {} |\n",
+            day,
+            year,
+            day,
+            format_cell(part(1)),
+            format_cell(part(2))
+        ));
+    }
+
+    let total: Duration = results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .map(|result| result.elapsed)
+        .sum();
+    table.push_str(&format!("\n**Total: {:.2?}**\n", total));
+    table
+}
+
+/// Replaces the contents between the two markers with `table`.
+pub fn update_section(readme: &str, table: &str) -> Result<String, String> {
+    let missing = || {
+        format!(
+            "could not find the section to update, add two lines containing \"{}\" to the readme",
+            MARKER
+        )
+    };
+
+    let start = readme.find(MARKER).ok_or_else(missing)? + MARKER.len();
+    let end = readme[start..].find(MARKER).ok_or_else(missing)? + start;
+
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+/// Rewrites the benchmarking section of the readme at `path`.
+pub fn update(path: &Path, year: i32, results: &[PartResult]) -> Result<(), String> {
+    let readme = fs::read_to_string(path).map_err(|e| format!("{}", e))?;
+    let updated = update_section(&readme, &render_table(year, results))?;
+    fs::write(path, updated).map_err(|e| format!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        let table = render_table(
+            2022,
+            &[
+                PartResult::answered(1, 1, Some("24000"), Duration::from_micros(50)),
+                PartResult::answered(1, 2, Some("45000"), Duration::from_micros(25)),
+                PartResult::answered(2, 1, Some("15"), Duration::from_micros(5)),
+                PartResult::answered(2, 2, None, Duration::from_micros(1)),
+                PartResult::answered(3, 1, None, Duration::from_micros(0)),
+                PartResult::answered(3, 2, None, Duration::from_micros(0)),
+            ],
+        );
+        assert_eq!(
+            table,
+            "## Benchmarks
+
+| Day | Part 1 | Part 2 |
+| :---: | :---: | :---: |
+| [Day 1](./src/y2022/01.rs) | `50.00µs` | `25.00µs` |
+| [Day 2](./src/y2022/02.rs) | `5.00µs` | - |
+
+**Total: 80.00µs**
+"
+        );
+    }
+
+    #[test]
+    fn test_update_section() {
+        let readme = format!("# Title\n{}\nold table\n{}\n## Footer\n", MARKER, MARKER);
+        assert_eq!(
+            update_section(&readme, "new table\n").unwrap(),
+            format!("# Title\n{}\nnew table\n{}\n## Footer\n", MARKER, MARKER)
+        );
+
+        let updated = update_section(&readme, "new table\n").unwrap();
+        assert_eq!(update_section(&updated, "new table\n").unwrap(), updated);
+
+        assert!(update_section("# Title\n", "table").is_err());
+        assert!(update_section(&format!("{}\n", MARKER), "table").is_err());
+    }
+}
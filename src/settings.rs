@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+// Parser for `aoc.toml`, the subset of TOML that is needed for a flat settings file.
+// Shared by `src/config.rs` and `build.rs`, so it must not depend on the rest of the crate.
+
+/// Value of a setting.
+#[derive(Debug, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+/// A `key = value` line of a settings file.
+#[derive(Debug, PartialEq)]
+pub struct Setting {
+    /// line number, starting at 1.
+    pub line: usize,
+    pub key: String,
+    pub value: Value,
+}
+
+fn parse_value(s: &str) -> Result<Value, String> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let string = quoted
+            .strip_suffix('"')
+            .filter(|string| !string.contains('"'))
+            .ok_or(format!("unterminated string {}", s))?;
+        return Ok(Value::String(string.to_string()));
+    }
+    match s {
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        _ => {
+            let number = s.replace('_', "");
+            number
+                .parse()
+                .map(Value::Integer)
+                .or_else(|_| number.parse().map(Value::Float))
+                .map_err(|_| format!("\"{}\" is not a string, number or boolean", s))
+        }
+    }
+}
+
+/// Removes a `#` comment that is not part of a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses the `key = value` lines of a settings file, skipping comments and empty lines.
+pub fn parse(contents: &str) -> Result<Vec<Setting>, String> {
+    let mut settings = vec![];
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |message: String| format!("line {}: {}", i + 1, message);
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(String::from("expected `key = value`")))?;
+        settings.push(Setting {
+            line: i + 1,
+            key: key.trim().to_string(),
+            value: parse_value(value.trim()).map_err(error)?,
+        });
+    }
+
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("# settings\n\nyear = 2_021\ndir = \"a#b\" # comment\ntimeout = 2.5\ncolor = false\n"),
+            Ok(vec![
+                Setting {
+                    line: 3,
+                    key: String::from("year"),
+                    value: Value::Integer(2021),
+                },
+                Setting {
+                    line: 4,
+                    key: String::from("dir"),
+                    value: Value::String(String::from("a#b")),
+                },
+                Setting {
+                    line: 5,
+                    key: String::from("timeout"),
+                    value: Value::Float(2.5),
+                },
+                Setting {
+                    line: 6,
+                    key: String::from("color"),
+                    value: Value::Boolean(false),
+                },
+            ])
+        );
+        assert_eq!(
+            parse("year = 2022\ncolor"),
+            Err(String::from("line 2: expected `key = value`"))
+        );
+        assert!(parse("dir = \"src").is_err());
+        assert!(parse("dir = src").is_err());
+    }
+}
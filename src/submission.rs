@@ -0,0 +1,555 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::{cache::session_hash, html, output::json_string};
+
+/// log of every submitted answer, relative to the repository root.
+pub const DEFAULT_LOG: &str = ".aoc/submissions.ndjson";
+
+/// time the website waits after a wrong answer before it accepts the next answer for a part.
+const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+/// the cooldown grows to `LONG_WRONG_ANSWER_COOLDOWN` after this many wrong answers for a part.
+const WRONG_ANSWERS_BEFORE_LONG_COOLDOWN: usize = 4;
+const LONG_WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(5 * 60);
+
+/// Verdict of the website on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint whether the answer is too high or too low.
+    Incorrect,
+    /// an answer was submitted too recently, the next one is accepted after the duration.
+    Wait(Duration),
+    /// the part was solved before, the website does not check answers for it anymore.
+    AlreadySolved,
+    /// the response could not be understood, holds the text of the response.
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Machine-readable name of the outcome, as written to the submission log.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::Wait(_) => "wait",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_name(name: &str, wait: Option<Duration>) -> Outcome {
+        match name {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "incorrect" => Outcome::Incorrect,
+            "wait" => Outcome::Wait(wait.unwrap_or_default()),
+            "already_solved" => Outcome::AlreadySolved,
+            other => Outcome::Unknown(other.to_string()),
+        }
+    }
+
+    /// `true` if the website rejected the answer as wrong.
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Outcome::Correct => String::from("That's the right answer! ⭐️"),
+            Outcome::TooHigh => {
+                String::from("That's not the right answer, your answer is too high.")
+            }
+            Outcome::TooLow => String::from("That's not the right answer, your answer is too low."),
+            Outcome::Incorrect => String::from("That's not the right answer."),
+            Outcome::Wait(duration) => format!(
+                "You gave an answer too recently, wait {}s before trying again.",
+                duration.as_secs()
+            ),
+            Outcome::AlreadySolved => String::from("This part is already solved."),
+            Outcome::Unknown(text) => format!("Unexpected response: {}", text),
+        }
+    }
+}
+
+/// Extracts the text of the first `<article>` of an HTML page, without tags and with collapsed whitespace.
+pub fn article_text(html: &str) -> String {
+    let article = html::articles(html).first().copied().unwrap_or(html);
+    html::strip_tags(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses a wait time like `4m 29s` or `34s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for token in text.split_whitespace() {
+        let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses the HTML page the website responds with after submitting an answer.
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait));
+        match wait {
+            Some(wait) => Outcome::Wait(wait),
+            None => Outcome::Unknown(text),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// A submitted answer and the verdict of the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+impl Submission {
+    /// Serializes the submission to a single line of JSON.
+    /// The session is only stored as a hash, to tell submissions of different accounts apart.
+    pub fn to_json(&self, session: &str) -> String {
+        let wait = match &self.outcome {
+            Outcome::Wait(wait) => wait.as_secs().to_string(),
+            _ => String::from("null"),
+        };
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"outcome\":\"{}\",\"wait_s\":{},\"session\":\"{}\",\"submitted_at\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.outcome.name(),
+            wait,
+            session_hash(session),
+            self.submitted_at
+        )
+    }
+}
+
+/// Parses a line of the submission log into the session hash and the submission.
+fn parse_line(line: &str) -> Result<(String, Submission), String> {
+    let value: JsonValue = line.parse().map_err(|e| format!("{}", e))?;
+    let object: &HashMap<String, JsonValue> = value.get().ok_or("expected an object")?;
+    let number = |key: &str| -> Result<f64, String> {
+        object
+            .get(key)
+            .and_then(|value| value.get::<f64>())
+            .copied()
+            .ok_or(format!("expected a number for \"{}\"", key))
+    };
+    let string = |key: &str| -> Result<String, String> {
+        object
+            .get(key)
+            .and_then(|value| value.get::<String>())
+            .cloned()
+            .ok_or(format!("expected a string for \"{}\"", key))
+    };
+
+    let wait = number("wait_s")
+        .ok()
+        .map(|seconds| Duration::from_secs(seconds as u64));
+    let submission = Submission {
+        year: number("year")? as i32,
+        day: number("day")? as u8,
+        part: number("part")? as u8,
+        answer: string("answer")?,
+        outcome: Outcome::from_name(&string("outcome")?, wait),
+        submitted_at: number("submitted_at")? as u64,
+    };
+    Ok((string("session")?, submission))
+}
+
+/// Previous submissions of one account, read from the submission log.
+#[derive(Debug, Default)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Parses the submission log, keeping only the submissions made with `session`.
+    pub fn parse(log: &str, session: &str) -> Result<History, String> {
+        let session = session_hash(session);
+        let mut submissions = vec![];
+        for (index, line) in log.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (line_session, submission) =
+                parse_line(line).map_err(|e| format!("line {}: {}", index + 1, e))?;
+            if line_session == session {
+                submissions.push(submission);
+            }
+        }
+        Ok(History { submissions })
+    }
+
+    /// Reads the submission log at `path`. A missing log is an empty history.
+    pub fn read(path: &Path, session: &str) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(log) => History::parse(&log, session),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("{}", e)),
+        }
+    }
+
+    /// Submissions for a part, oldest first.
+    pub fn attempts(&self, year: i32, day: u8, part: u8) -> Vec<&Submission> {
+        self.submissions
+            .iter()
+            .filter(|submission| {
+                submission.year == year && submission.day == day && submission.part == part
+            })
+            .collect()
+    }
+
+    /// Fails if submitting `answer` at `now` (seconds since the unix epoch) cannot be right
+    /// or would be rejected by the website, based on the previous submissions for the part.
+    pub fn check(
+        &self,
+        year: i32,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        let attempts = self.attempts(year, day, part);
+
+        if let Some(correct) = attempts
+            .iter()
+            .find(|attempt| attempt.outcome == Outcome::Correct)
+        {
+            return Err(format!(
+                "the part was already solved with \"{}\"",
+                correct.answer
+            ));
+        }
+
+        if let Some(rejected) = attempts
+            .iter()
+            .find(|attempt| attempt.answer == answer && attempt.outcome.is_rejected())
+        {
+            let reason = match rejected.outcome {
+                Outcome::TooHigh => "too high",
+                Outcome::TooLow => "too low",
+                _ => "not the right answer",
+            };
+            return Err(format!("\"{}\" was already rejected as {}", answer, reason));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: Outcome| {
+                attempts
+                    .iter()
+                    .filter(move |attempt| attempt.outcome == outcome)
+                    .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Outcome::TooHigh).min().filter(|&high| value >= high) {
+                return Err(format!(
+                    "{} was already too high, the answer is below it",
+                    high
+                ));
+            }
+            if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| value <= low) {
+                return Err(format!(
+                    "{} was already too low, the answer is above it",
+                    low
+                ));
+            }
+        }
+
+        if let Some(next_allowed) = self
+            .next_allowed(year, day, part)
+            .filter(|&next_allowed| next_allowed > now)
+        {
+            return Err(format!(
+                "the website does not accept answers yet, try again in {}s",
+                next_allowed - now
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Time (seconds since the unix epoch) from which the website accepts the next answer for a part:
+    /// after the wait it asked for, or after the cooldown that follows the last wrong answer.
+    pub fn next_allowed(&self, year: i32, day: u8, part: u8) -> Option<u64> {
+        let attempts = self.attempts(year, day, part);
+
+        let asked = attempts
+            .iter()
+            .filter_map(|attempt| match attempt.outcome {
+                Outcome::Wait(wait) => Some(attempt.submitted_at + wait.as_secs()),
+                _ => None,
+            })
+            .max();
+
+        let rejected: Vec<&&Submission> = attempts
+            .iter()
+            .filter(|attempt| attempt.outcome.is_rejected())
+            .collect();
+        let cooldown = if rejected.len() >= WRONG_ANSWERS_BEFORE_LONG_COOLDOWN {
+            LONG_WRONG_ANSWER_COOLDOWN
+        } else {
+            WRONG_ANSWER_COOLDOWN
+        };
+        let cooled_down = rejected
+            .last()
+            .map(|attempt| attempt.submitted_at + cooldown.as_secs());
+
+        asked.max(cooled_down)
+    }
+}
+
+/// Appends a submission to the log at `path`.
+pub fn record(path: &Path, submission: &Submission, session: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", submission.to_json(session))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html><html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. [<a href=\"/2022/day/1\">Return to Day 1</a>]")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 29s left to wait. [<a href=\"/2022/day/1\">Return to Day 1</a>]")),
+            Outcome::Wait(Duration::from_secs(269))
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.")),
+            Outcome::Wait(Duration::from_secs(34))
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2022/day/1\">Return to Day 1</a>]")),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_response("<html>Maintenance</html>"),
+            Outcome::Unknown(String::from("Maintenance"))
+        );
+    }
+
+    #[test]
+    fn test_article_text() {
+        assert_eq!(
+            article_text("<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2>\n<p>Santa&#39;s &quot;elves&quot;</p></article><p>footer</p></main>"),
+            "--- Day 1 --- Santa's \"elves\""
+        );
+    }
+
+    fn submission(part: u8, answer: &str, outcome: Outcome, submitted_at: u64) -> Submission {
+        Submission {
+            year: 2022,
+            day: 1,
+            part,
+            answer: String::from(answer),
+            outcome,
+            submitted_at,
+        }
+    }
+
+    #[test]
+    fn test_history_parse() {
+        let log = [
+            submission(1, "100", Outcome::TooLow, 10).to_json("abc123"),
+            submission(1, "MCD", Outcome::Wait(Duration::from_secs(30)), 20).to_json("abc123"),
+            submission(1, "200", Outcome::TooHigh, 30).to_json("other"),
+            String::new(),
+        ]
+        .join("\n");
+        let history = History::parse(&log, "abc123").unwrap();
+
+        assert_eq!(
+            history.attempts(2022, 1, 1),
+            vec![
+                &submission(1, "100", Outcome::TooLow, 10),
+                &submission(1, "MCD", Outcome::Wait(Duration::from_secs(30)), 20)
+            ]
+        );
+        assert!(history.attempts(2022, 1, 2).is_empty());
+        assert!(History::parse("{\"year\":2022}", "abc123").is_err());
+    }
+
+    #[test]
+    fn test_history_check() {
+        let history = History {
+            submissions: vec![
+                submission(1, "100", Outcome::TooLow, 10),
+                submission(1, "500", Outcome::TooHigh, 20),
+                submission(1, "300", Outcome::Incorrect, 30),
+                submission(1, "MCD", Outcome::Incorrect, 40),
+                submission(1, "400", Outcome::Wait(Duration::from_secs(60)), 50),
+                submission(2, "7", Outcome::Correct, 60),
+            ],
+        };
+        let check = |part: u8, answer: &str, now: u64| history.check(2022, 1, part, answer, now);
+
+        assert_eq!(check(1, "250", 400), Ok(()));
+        assert_eq!(check(1, "CMZ", 400), Ok(()));
+        assert_eq!(
+            check(1, "300", 200),
+            Err(String::from(
+                "\"300\" was already rejected as not the right answer"
+            ))
+        );
+        assert_eq!(
+            check(1, "500", 200),
+            Err(String::from("\"500\" was already rejected as too high"))
+        );
+        assert!(check(1, "MCD", 200).is_err());
+        assert_eq!(
+            check(1, "501", 200),
+            Err(String::from(
+                "500 was already too high, the answer is below it"
+            ))
+        );
+        assert_eq!(
+            check(1, "99", 200),
+            Err(String::from(
+                "100 was already too low, the answer is above it"
+            ))
+        );
+        assert_eq!(
+            check(1, "250", 200),
+            Err(String::from(
+                "the website does not accept answers yet, try again in 140s"
+            ))
+        );
+        assert_eq!(
+            check(2, "8", 200),
+            Err(String::from("the part was already solved with \"7\""))
+        );
+    }
+
+    #[test]
+    fn test_next_allowed() {
+        let mut history = History {
+            submissions: vec![submission(1, "100", Outcome::TooLow, 10)],
+        };
+        assert_eq!(history.next_allowed(2022, 1, 1), Some(70));
+        assert_eq!(history.next_allowed(2022, 1, 2), None);
+        assert_eq!(
+            history.check(2022, 1, 1, "200", 40),
+            Err(String::from(
+                "the website does not accept answers yet, try again in 30s"
+            ))
+        );
+        assert_eq!(history.check(2022, 1, 1, "200", 70), Ok(()));
+
+        // the website asked to wait longer than the cooldown.
+        history.submissions.push(submission(
+            1,
+            "200",
+            Outcome::Wait(Duration::from_secs(120)),
+            30,
+        ));
+        assert_eq!(history.next_allowed(2022, 1, 1), Some(150));
+
+        // the cooldown grows after several wrong answers.
+        for (answer, at) in [("110", 200), ("120", 300), ("130", 400)] {
+            history
+                .submissions
+                .push(submission(1, answer, Outcome::TooLow, at));
+        }
+        assert_eq!(history.next_allowed(2022, 1, 1), Some(700));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let submission = Submission {
+            year: 2022,
+            day: 1,
+            part: 2,
+            answer: String::from("45000"),
+            outcome: Outcome::Wait(Duration::from_secs(34)),
+            submitted_at: 1_669_870_800,
+        };
+        assert_eq!(
+            submission.to_json("abc123"),
+            "{\"year\":2022,\"day\":1,\"part\":2,\"answer\":\"45000\",\"outcome\":\"wait\",\"wait_s\":34,\"session\":\"6ca13d52ca70c883\",\"submitted_at\":1669870800}"
+        );
+    }
+}
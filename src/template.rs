@@ -0,0 +1,142 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt, str::FromStr};
+
+/// template for new solutions, relative to the repository root.
+pub const DEFAULT_TEMPLATE: &str = "templates/day.rs.tmpl";
+
+/// Type that the solvers of a scaffolded day return, selected with `--type`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnswerType {
+    U32,
+    U64,
+    I64,
+    String,
+}
+
+impl FromStr for AnswerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(AnswerType::U32),
+            "u64" => Ok(AnswerType::U64),
+            "i64" => Ok(AnswerType::I64),
+            "String" | "string" => Ok(AnswerType::String),
+            _ => Err(format!(
+                "unknown type \"{}\", expected one of: u32, u64, i64, String",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AnswerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AnswerType::U32 => "u32",
+            AnswerType::U64 => "u64",
+            AnswerType::I64 => "i64",
+            AnswerType::String => "String",
+        })
+    }
+}
+
+impl AnswerType {
+    /// Renders an answer as a Rust expression of type `Option<Self>`.
+    /// Returns `None` if the answer is not a value of this type.
+    pub fn expression(&self, answer: &str) -> Option<String> {
+        let is_valid = match self {
+            AnswerType::U32 => answer.parse::<u32>().is_ok(),
+            AnswerType::U64 => answer.parse::<u64>().is_ok(),
+            AnswerType::I64 => answer.parse::<i64>().is_ok(),
+            AnswerType::String => {
+                return Some(format!("Some(String::from({:?}))", answer));
+            }
+        };
+        is_valid.then(|| format!("Some({})", answer))
+    }
+}
+
+/// Replaces every `{{name}}` in a template with the value of the variable `name`.
+/// Unknown variables are errors, so typos in a template do not end up in generated code.
+pub fn render(template: &str, variables: &[(&str, String)]) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or("unterminated \"{{\" in template")?;
+        let name = rest[start + 2..start + end].trim();
+        let value = variables
+            .iter()
+            .find(|(variable, _)| *variable == name)
+            .map(|(_, value)| value)
+            .ok_or(format!("unknown template variable \"{}\"", name))?;
+        out.push_str(value);
+        rest = &rest[start + end + 2..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let variables = [
+            ("day", String::from("5")),
+            ("day_padded", String::from("05")),
+        ];
+        assert_eq!(
+            render(
+                "read_file(\"examples\", {{day}}) // {{ day_padded }}.rs",
+                &variables
+            ),
+            Ok(String::from("read_file(\"examples\", 5) // 05.rs"))
+        );
+        assert_eq!(
+            render("no variables", &variables),
+            Ok(String::from("no variables"))
+        );
+        assert_eq!(
+            render("{{title}}", &variables),
+            Err(String::from("unknown template variable \"title\""))
+        );
+        assert!(render("{{day", &variables).is_err());
+    }
+
+    #[test]
+    fn test_answer_type() {
+        assert_eq!("u64".parse(), Ok(AnswerType::U64));
+        assert_eq!("String".parse(), Ok(AnswerType::String));
+        assert!("usize".parse::<AnswerType>().is_err());
+        assert_eq!(AnswerType::I64.to_string(), "i64");
+
+        assert_eq!(
+            AnswerType::U32.expression("4000"),
+            Some(String::from("Some(4000)"))
+        );
+        assert_eq!(AnswerType::U32.expression("-3"), None);
+        assert_eq!(
+            AnswerType::I64.expression("-3"),
+            Some(String::from("Some(-3)"))
+        );
+        assert_eq!(AnswerType::U32.expression("5000000000"), None);
+        assert_eq!(
+            AnswerType::U64.expression("5000000000"),
+            Some(String::from("Some(5000000000)"))
+        );
+        assert_eq!(
+            AnswerType::String.expression("CMZ"),
+            Some(String::from("Some(String::from(\"CMZ\"))"))
+        );
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, io};
+
+use crate::{try_read_file, year_dir, PartResult};
+
+/// Parses the contents of an answers file: the first line holds the answer to part one,
+/// the second line the answer to part two. Empty lines mark unknown answers.
+pub fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(|line| {
+        let line = line.trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some(line.to_string())
+        }
+    });
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// Reads the stored answers for a day from `src/y{year}/answers/{day:02}.txt`.
+pub fn read_answers(year: i32, day: u8) -> [Option<String>; 2] {
+    try_read_file("answers", year, day)
+        .map(|contents| parse_answers(&contents))
+        .unwrap_or_default()
+}
+
+/// Formats answers in the layout read by [`parse_answers`].
+pub fn format_answers(answers: &[Option<String>; 2]) -> String {
+    answers
+        .iter()
+        .map(|answer| format!("{}\n", answer.as_deref().unwrap_or_default()))
+        .collect()
+}
+
+/// Stores the answer to a part in `src/y{year}/answers/{day:02}.txt`, keeping the answer to the other part.
+pub fn save_answer(year: i32, day: u8, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = read_answers(year, day);
+    answers[usize::from(part == 2)] = Some(answer.to_string());
+
+    let dir = env::current_dir()?.join(year_dir(year)).join("answers");
+    fs::create_dir_all(&dir)?;
+    fs::write(
+        dir.join(format!("{:02}.txt", day)),
+        format_answers(&answers),
+    )
+}
+
+/// Fills in the stored answer of every result so it can be checked with [`PartResult::is_correct`].
+pub fn check(year: i32, results: &mut [PartResult]) {
+    for result in results.iter_mut() {
+        let [part_one, part_two] = read_answers(year, result.day);
+        result.expected = match result.part {
+            1 => part_one,
+            2 => part_two,
+            _ => None,
+        };
+    }
+}
+
+/// `true` if any result does not match its stored answer.
+pub fn has_regression(results: &[PartResult]) -> bool {
+    results
+        .iter()
+        .any(|result| result.is_correct() == Some(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("24000\n45000\n"),
+            [Some(String::from("24000")), Some(String::from("45000"))]
+        );
+        assert_eq!(
+            parse_answers("\r\nMCD\r\n"),
+            [None, Some(String::from("MCD"))]
+        );
+        assert_eq!(parse_answers("CMZ"), [Some(String::from("CMZ")), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_format_answers() {
+        let answers = [None, Some(String::from("MCD"))];
+        assert_eq!(format_answers(&answers), "\nMCD\n");
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_has_regression() {
+        let mut result = PartResult::answered(1, 1, Some("1"), Duration::ZERO);
+        assert!(!has_regression(&[result.clone()]));
+
+        result.expected = Some(String::from("1"));
+        assert!(!has_regression(&[result.clone()]));
+
+        result.expected = Some(String::from("2"));
+        assert!(has_regression(&[result.clone()]));
+
+        result.answer = None;
+        assert!(has_regression(&[result]));
+    }
+}
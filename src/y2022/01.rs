@@ -0,0 +1,75 @@
+use advent_of_code::input::ParseError;
+use std::cmp::max;
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    advent_of_code::validate_lines!(input, r"^\d*$")?;
+    let mut elfs: Vec<_> = input.lines().collect();
+    let mut buff: Vec<u32> = vec![];
+    let mut result: Option<u32> = None;
+    elfs.push("");
+
+    for i in elfs {
+        match i {
+            "" => {
+                let sum: u32 = buff.iter().sum();
+                buff = vec![];
+                result = result.or(Some(0)).map(|a| max(a, sum))
+            }
+            num => buff.push(num.parse::<u32>().unwrap()),
+        }
+    }
+    Ok(result)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    advent_of_code::validate_lines!(input, r"^\d*$")?;
+    let mut elfs: Vec<_> = input.lines().collect();
+    let mut buff: Vec<u32> = vec![];
+    let mut result: Vec<u32> = vec![];
+    elfs.push("");
+
+    for i in elfs {
+        match i {
+            "" => {
+                let sum: u32 = buff.iter().sum();
+                buff = vec![];
+                result.push(sum);
+            }
+            num => buff.push(num.parse::<u32>().unwrap()),
+        }
+    }
+
+    result.sort();
+
+    Ok(Some(result.iter().rev().take(3).sum()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_one(&input), Ok(Some(24000)));
+    }
+
+    #[test]
+    fn test_part_one_last_elf() {
+        assert_eq!(part_one("1000\n\n2000\n3000"), Ok(Some(5000)));
+    }
+
+    #[test]
+    fn test_part_one_invalid() {
+        assert_eq!(
+            part_one("1000\n\n20OO\n").unwrap_err().to_string(),
+            r#"line 3: "20OO" does not match `^\d*$`"#
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!(part_two(&input), Ok(Some(45000)));
+    }
+}
@@ -0,0 +1,112 @@
+use advent_of_code::input::{self, ParseError};
+
+/// Splits a round `A X` into the opponent's pick and the second column.
+fn parse_round(line: &str) -> Result<(&str, &str), ParseError> {
+    let (oponent_pick, second_column) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(format!("expected `A X`, got {:?}", line)))?;
+    match oponent_pick {
+        "A" | "B" | "C" => Ok((oponent_pick, second_column)),
+        _ => Err(ParseError::new(format!("unknown pick {:?}", oponent_pick)).at_column(1)),
+    }
+}
+
+fn convert_pick(pick: &str) -> Result<&str, ParseError> {
+    match pick {
+        "X" => Ok("A"),
+        "Y" => Ok("B"),
+        "Z" => Ok("C"),
+        _ => Err(ParseError::new(format!("unknown pick {:?}", pick)).at_column(3)),
+    }
+}
+
+fn get_point_for_pick(pick: &str) -> u32 {
+    match pick {
+        "A" => 1,
+        "B" => 2,
+        "C" => 3,
+        _ => unreachable!("picks are checked while parsing"),
+    }
+}
+
+fn get_round_result(oponent_pick: &str, player_pick: &str) -> u32 {
+    (match (oponent_pick, player_pick) {
+        (x, y) if x == y => 3,
+        ("A", "B") => 6,
+        ("A", "C") => 0,
+        ("B", "A") => 0,
+        ("B", "C") => 6,
+        ("C", "A") => 6,
+        ("C", "B") => 0,
+        (_, _) => unreachable!("picks are checked while parsing"),
+    }) + get_point_for_pick(player_pick)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let results = input::parse_lines(input, |line| {
+        let (oponent_pick, player_pick) = parse_round(line)?;
+        Ok(get_round_result(oponent_pick, convert_pick(player_pick)?))
+    })?;
+    Ok(Some(results.iter().sum()))
+}
+
+fn pick_by_strategy<'a>(strategy: &'a str, oponent_pick: &'a str) -> Result<&'a str, ParseError> {
+    match (strategy, oponent_pick) {
+        ("Y", x) => Ok(x),
+        ("X", "A") => Ok("C"),
+        ("X", "B") => Ok("A"),
+        ("X", "C") => Ok("B"),
+        ("Z", "A") => Ok("B"),
+        ("Z", "B") => Ok("C"),
+        ("Z", "C") => Ok("A"),
+
+        _ => Err(ParseError::new(format!("unknown strategy {:?}", strategy)).at_column(3)),
+    }
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let results = input::parse_lines(input, |line| {
+        let (oponent_pick, strategy) = parse_round(line)?;
+        Ok(get_round_result(
+            oponent_pick,
+            pick_by_strategy(strategy, oponent_pick)?,
+        ))
+    })?;
+    Ok(Some(results.iter().sum()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_one(&input), Ok(Some(15)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_two(&input), Ok(Some(12)));
+    }
+
+    #[test]
+    fn test_invalid_pick() {
+        let input = advent_of_code::read_file("examples", 2022, 2).replace("B X", "B W");
+        assert_eq!(
+            part_one(&input).unwrap_err().to_string(),
+            "line 2, column 3: unknown pick \"W\""
+        );
+        assert_eq!(
+            part_two(&input).unwrap_err().to_string(),
+            "line 2, column 3: unknown strategy \"W\""
+        );
+
+        let input = advent_of_code::read_file("examples", 2022, 2).replace("C Z", "D Z");
+        assert_eq!(
+            part_one(&input).unwrap_err().to_string(),
+            "line 3, column 1: unknown pick \"D\""
+        );
+    }
+}
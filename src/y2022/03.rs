@@ -0,0 +1,99 @@
+use advent_of_code::input::{self, ParseError};
+
+/// Checks that a rucksack only holds items `a-z` and `A-Z`.
+fn parse_rucksack(rucksack: &str) -> Result<&str, ParseError> {
+    match rucksack
+        .chars()
+        .enumerate()
+        .find(|(_, item)| !item.is_ascii_alphabetic())
+    {
+        Some((i, item)) => {
+            Err(ParseError::new(format!("unknown item {:?}", item)).at_column(i + 1))
+        }
+        None => Ok(rucksack),
+    }
+}
+
+fn get_char_code(letter: Vec<char>) -> u8 {
+    letter.first().map_or(0, |letter| {
+        let n = *letter as u8;
+        // items are checked to be `a-z` or `A-Z` while parsing.
+        if letter.is_ascii_lowercase() {
+            n - 96
+        } else {
+            (n - 64) + 26
+        }
+    })
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let rucksacks = input::parse_lines(input, parse_rucksack)?;
+    Ok(Some(rucksacks.iter().fold(0, |sum, rucksack| {
+        let (first_part, second_part) = (
+            &rucksack[0..(rucksack.len() / 2)],
+            &rucksack[rucksack.len() / 2..rucksack.len()],
+        );
+        let mut result: Vec<char> = first_part
+            .chars()
+            .filter(|c| second_part.contains(&c.to_string()))
+            .collect();
+        result.dedup();
+        sum + get_char_code(result) as u32
+    })))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let rucksacks = input::parse_lines(input, parse_rucksack)?;
+    Ok(Some(rucksacks.chunks(3).fold(0, |sum, chunk| {
+        let mut chunk_sacks = Vec::from(chunk);
+        chunk_sacks.sort();
+
+        match chunk_sacks.last() {
+            Some(l) => {
+                let mut result: Vec<char> = l
+                    .chars()
+                    .filter(|c| {
+                        chunk_sacks[0..chunk_sacks.len() - 1]
+                            .iter()
+                            .all(|sack| sack.contains(&c.to_string()))
+                    })
+                    .collect();
+                result.dedup();
+                sum + get_char_code(result) as u32
+            }
+            None => panic!("cannot find longest str"),
+        }
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+
+        assert_eq!(part_one(&input), Ok(Some(157)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 3);
+        assert_eq!(part_two(&input), Ok(Some(70)));
+    }
+
+    #[test]
+    fn test_invalid_item() {
+        let input = advent_of_code::read_file("examples", 2022, 3)
+            .replace("PmmdzqPrVvPwwTWBwg", "PmmdzqPr-vPwwTWBwg");
+        assert_eq!(
+            part_one(&input).unwrap_err().to_string(),
+            "line 3, column 9: unknown item '-'"
+        );
+        assert_eq!(
+            part_two(&input).unwrap_err().to_string(),
+            "line 3, column 9: unknown item '-'"
+        );
+    }
+}
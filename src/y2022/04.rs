@@ -76,25 +76,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     }))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_two(&input), Some(4));
     }
 }
@@ -1,3 +1,4 @@
+use advent_of_code::input::ParseError;
 use lazy_static::lazy_static;
 use regex::{Match, Regex};
 use std::collections::{HashMap, LinkedList};
@@ -54,7 +55,7 @@ struct Command {
 }
 
 impl Command {
-    fn from(command: &str) -> Command {
+    fn parse(command: &str) -> Result<Command, ParseError> {
         lazy_static! {
             static ref RE: Regex = Regex::new(
                 r"move (?P<amount>\d+) from (?P<stack_from_num>\d+) to (?P<stack_to_num>\d+)"
@@ -62,13 +63,22 @@ impl Command {
             .unwrap();
         }
 
-        RE.captures(command)
-            .map(|cap| Command {
-                amount: parse_capture(cap.name("amount")).unwrap(),
-                stack_from_num: parse_capture(cap.name("stack_from_num")).unwrap(),
-                stack_to_num: parse_capture(cap.name("stack_to_num")).unwrap(),
-            })
-            .unwrap()
+        let error = || ParseError::new(format!("expected `move N from A to B`, got {:?}", command));
+        let cap = RE.captures(command).ok_or_else(error)?;
+        Ok(Command {
+            amount: parse_capture(cap.name("amount")).ok_or_else(error)?,
+            stack_from_num: parse_capture(cap.name("stack_from_num")).ok_or_else(error)?,
+            stack_to_num: parse_capture(cap.name("stack_to_num")).ok_or_else(error)?,
+        })
+    }
+
+    /// Parses the moves that follow the drawing of the stacks, numbering lines from the start of the input.
+    fn parse_moves(cargo: &str, moves: &str) -> Result<Vec<Command>, ParseError> {
+        let first_line = cargo.lines().count() + 1;
+        advent_of_code::input::parse_lines(moves, Command::parse).map_err(|e| {
+            let line = first_line + e.line.unwrap_or_default();
+            e.at_line(line)
+        })
     }
 }
 
@@ -109,48 +119,36 @@ fn get_result(stacks: HashMap<i32, LinkedList<&str>>) -> String {
         .join("")
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let parts: Vec<_> = input.split("\n\n").collect();
-    let cargo = parts.first();
-    let moves = parts.last().unwrap();
-
-    let matrix = cargo.map(|c| build_matrix(c));
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    let (cargo, moves) = input.split_once("\n\n").ok_or(ParseError::new(
+        "expected the stacks and the moves, separated by an empty line",
+    ))?;
 
-    let inverted_cargo = matrix.map(|m| invert_cargo(m)).unwrap();
+    let inverted_cargo = invert_cargo(build_matrix(cargo));
 
     let mut stacks = build_stacks(inverted_cargo);
 
-    for line in moves.lines() {
-        let cmd = Command::from(line);
+    for cmd in Command::parse_moves(cargo, moves)? {
         stacks = move_stacks(stacks, &cmd);
     }
 
-    Some(get_result(stacks))
+    Ok(Some(get_result(stacks)))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let parts: Vec<_> = input.split("\n\n").collect();
-    let cargo = parts.first();
-    let moves = parts.last().unwrap();
-
-    let matrix = cargo.map(|c| build_matrix(c));
+pub fn part_two(input: &str) -> Result<Option<String>, ParseError> {
+    let (cargo, moves) = input.split_once("\n\n").ok_or(ParseError::new(
+        "expected the stacks and the moves, separated by an empty line",
+    ))?;
 
-    let inverted_cargo = matrix.map(|m| invert_cargo(m)).unwrap();
+    let inverted_cargo = invert_cargo(build_matrix(cargo));
 
     let mut stacks = build_stacks(inverted_cargo);
 
-    for line in moves.lines() {
-        let cmd = Command::from(line);
+    for cmd in Command::parse_moves(cargo, moves)? {
         stacks = move_stacks_with_preserve(stacks, &cmd);
     }
 
-    Some(get_result(stacks))
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Ok(Some(get_result(stacks)))
 }
 
 #[cfg(test)]
@@ -159,13 +157,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some(String::from("CMZ")));
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_one(&input), Ok(Some(String::from("CMZ"))));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some(String::from("MCD")));
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_two(&input), Ok(Some(String::from("MCD"))));
+    }
+
+    #[test]
+    fn test_invalid_move() {
+        let input = advent_of_code::read_file("examples", 2022, 5)
+            .replace("move 3 from 1 to 3", "move 3 from 1");
+        assert_eq!(
+            part_one(&input).unwrap_err().to_string(),
+            "line 7: expected `move N from A to B`, got \"move 3 from 1\""
+        );
     }
 }
@@ -136,36 +136,25 @@ pub fn part_two(input: &str) -> Option<u32> {
     let root_folder = build_tree(input);
     let total_size = root_folder.borrow().get_folder_size();
     let min_size = 30_000_000 - (70_000_000 - total_size);
-    println!("total size: {}, min size: {}", total_size, min_size);
     let closest = root_folder
         .borrow()
         .get_closest_folder_to_size(threshhold, min_size) as u32;
     Some(closest)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    let threshhold = 30000000;
-    let mut sizes = vec![584, 94853, 24933642, 48381165, 48381167];
-    sizes.sort_by(|a, b| (((*a - threshhold) as i32).abs()).cmp(&((*b - threshhold) as i32).abs()));
-    println!("{:?}", sizes);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
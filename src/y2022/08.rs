@@ -143,25 +143,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(max_n)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", 2022, 8);
         assert_eq!(part_one(&input), Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", 2022, 8);
         assert_eq!(part_two(&input), Some(8));
     }
 }
@@ -1,8 +1,6 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-use rgb::RGB8;
 use std::{collections::HashSet, iter};
-use textplots::{Chart, ColorPlot, Plot, Shape};
 
 #[derive(Copy, Clone, Debug)]
 enum Direction {
@@ -103,18 +101,14 @@ impl Point {
     }
 
     fn follow(&self, other: &Point) -> Point {
-        if self.distance(other) <= 1 {
-            return self.clone();
+        if self.is_adjacent_to(other) {
+            *self
         } else {
-            let mut x = self.x.clamp(other.x - 1, other.x + 1);
-            let mut y = self.y.clamp(other.y - 1, other.y + 1);
-
-            if x == self.x {
-                x = other.x
-            } else if y == self.y {
-                y = other.y
-            }
-            Point::new(x, y)
+            // a knot that is not touching moves one step towards the knot in front, diagonally if needed.
+            Point::new(
+                self.x + (other.x - self.x).signum(),
+                self.y + (other.y - self.y).signum(),
+            )
         }
     }
 }
@@ -128,20 +122,6 @@ fn simulate_rope(commands: Vec<Direction>) -> i32 {
             .fold((HashSet::new(), head, tail), |(mut set, head, tail), d| {
                 let new_head = head.move_point(d);
                 let new_tail = tail.follow(&new_head);
-
-                Chart::new_with_y_range(180, 60, -6.0, 6.0, -6.0, 6.0)
-                    .linecolorplot(
-                        &Shape::Points(&[
-                            (new_tail.x as f32, new_tail.y as f32),
-                            (new_head.x as f32, new_head.y as f32),
-                        ]),
-                        RGB8 {
-                            r: 255_u8,
-                            g: 0,
-                            b: 0,
-                        },
-                    )
-                    .nice();
                 set.insert(new_tail);
 
                 (set, new_head, new_tail)
@@ -156,8 +136,7 @@ fn simulate_rope_n(commands: Vec<Direction>, particles: usize) -> i32 {
         commands
             .iter()
             .fold((HashSet::new(), particles), |(mut set, particles), d| {
-                // TODO: apply movement for each particle
-                // let mut head: Option<&Point> = None;
+                // the head moves first, every other particle follows the one in front of it.
                 let (_, new_particles) = particles.iter().fold(
                     (None, Vec::new()),
                     |(head, result): (Option<Point>, Vec<Point>), particle| {
@@ -165,30 +144,16 @@ fn simulate_rope_n(commands: Vec<Direction>, particles: usize) -> i32 {
                             let new_particle = particle.follow(&h);
                             (
                                 Some(new_particle),
-                                vec![vec![new_particle], result].concat(),
+                                vec![result, vec![new_particle]].concat(),
                             )
                         } else {
                             let new_head = particle.move_point(d);
 
-                            (Some(new_head), vec![vec![new_head], result].concat())
+                            (Some(new_head), vec![result, vec![new_head]].concat())
                         }
                     },
                 );
-                let points = new_particles
-                    .iter()
-                    .map(|p| (p.x as f32, p.y as f32))
-                    .collect::<Vec<_>>();
-                Chart::new_with_y_range(180, 60, 0.0, 6.0, 0.0, 6.0)
-                    .linecolorplot(
-                        &Shape::Points(points.as_slice()),
-                        RGB8 {
-                            r: 255_u8,
-                            g: 0,
-                            b: 0,
-                        },
-                    )
-                    .nice();
-                set.insert(*new_particles.first().unwrap());
+                set.insert(*new_particles.last().unwrap());
 
                 (set, new_particles)
             });
@@ -217,25 +182,22 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(number_of_points as u32)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = advent_of_code::read_example(2022, 9, 1);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(0));
+        let input = advent_of_code::read_example(2022, 9, 2);
+        assert_eq!(part_two(&input), Some(36));
+
+        let input = advent_of_code::read_example(2022, 9, 1);
+        assert_eq!(part_two(&input), Some(1));
     }
 }
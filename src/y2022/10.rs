@@ -70,15 +70,7 @@ pub fn part_two(input: &str) -> Option<String> {
         }
         res.push(crt_row.join(""));
     }
-    println!("{}", res.join("\n"));
-
-    None
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Some(res.join("\n"))
 }
 
 #[cfg(test)]
@@ -87,13 +79,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        assert_eq!(
+            part_two(&input),
+            Some(String::from(
+                "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+            ))
+        );
     }
 }
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::process::Command;
+
+use tinyjson::JsonValue;
+
+/// Solvers share stdout with the runner, so a solver that prints would corrupt `--format json`.
+#[test]
+fn test_json_output_of_every_solved_day() {
+    for day in 1..=25 {
+        let example = format!("src/y2022/examples/{:02}.txt", day);
+        if !std::path::Path::new(&example).exists() {
+            continue;
+        }
+
+        let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+            .args(["2022", &day.to_string(), "--format", "json", "--input"])
+            .arg(&example)
+            .output()
+            .expect("could not run the runner");
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        let document: JsonValue = stdout
+            .parse()
+            .unwrap_or_else(|e| panic!("day {}: invalid JSON: {}\n{}", day, e, stdout));
+        let parts: &Vec<JsonValue> = document
+            .get::<HashMap<String, JsonValue>>()
+            .and_then(|document| document.get("parts"))
+            .and_then(|parts| parts.get())
+            .unwrap_or_else(|| panic!("day {}: no \"parts\" array in {}", day, stdout));
+        assert_eq!(parts.len(), 2, "day {}: {}", day, stdout);
+    }
+}